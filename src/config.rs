@@ -1,26 +1,142 @@
-use std::{fmt, path::PathBuf};
+use std::fmt;
 
-use ahash::AHashSet;
+use ahash::{AHashMap, AHashSet};
 use serde::{
     de,
     de::{value, SeqAccess, Visitor},
     Deserialize, Deserializer,
 };
 
+use crate::path::{glob_match, is_glob, resolve_config_path, strip_module_suffix};
+
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Binding {
     #[serde(default)]
     pub scope_replace: bool,
+    /// Entry points without scope support. Built-in libraries have defaults.
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_optional_import_names")]
+    pub no_scope: Option<AHashSet<String>>,
+    /// Entry point `no_scope` imports are replaced with.
+    pub scope: Option<String>,
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_binding_methods")]
+    pub methods: AHashMap<String, BindingMethod>,
+}
+
+/// Rewriting of a single method imported from a view library.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct BindingMethod {
+    /// Method imported instead, e.g. `useUnit` for `useStore`.
+    pub name: Option<String>,
+    /// Import the method from the `scope` entry point of the library.
     #[serde(default)]
-    pub methods: AHashSet<String>,
+    pub scope: bool,
+}
+
+/// View library bindings by library name, e.g. `react`.
+pub type Bindings = AHashMap<String, Binding>;
+
+/// Either a single export of a module (`module#export`) or every export of it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ImportSelector {
+    pub source: String,
+    pub export: Option<String>,
+}
+
+impl From<&str> for ImportSelector {
+    fn from(selector: &str) -> Self {
+        // Node subpath imports start with `#`, so only a later `#` separates
+        // the export, e.g. `#shared/factories#createToggle`
+        let (source, export) = match selector.rfind('#').filter(|&index| index > 0) {
+            Some(index) => (&selector[..index], Some(selector[index + 1..].to_owned())),
+            None => (selector, None),
+        };
+
+        // Directory prefixes match every module inside of them
+        let source = match source.strip_suffix('/') {
+            Some(dir) => format!("{dir}/**"),
+            None => strip_module_suffix(source),
+        };
+
+        Self { source, export }
+    }
+}
+
+impl ImportSelector {
+    /// `source` is expected to be resolved with `resolve_import_sources`.
+    pub fn matches(&self, root: &str, source: &str, export: &str) -> bool {
+        let export_matches = match &self.export {
+            Some(e) => e == export,
+            None => true,
+        };
+
+        export_matches && self.matches_module(root, source)
+    }
+
+    pub fn matches_module(&self, root: &str, source: &str) -> bool {
+        let pattern = resolve_config_path(root, &self.source);
+
+        if is_glob(&pattern) {
+            glob_match(&pattern, source)
+        } else {
+            pattern == source
+        }
+    }
 }
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct Bindings {
-    pub react: Option<Binding>,
-    pub solid: Option<Binding>,
+pub struct Naming {
+    #[serde(default)]
+    pub template: Option<String>,
+    #[serde(default)]
+    pub strip_dollar_prefix: bool,
+    #[serde(default)]
+    pub strip_fx_suffix: bool,
+    #[serde(default = "default_anonymous_name")]
+    pub anonymous: String,
+}
+
+impl Default for Naming {
+    fn default() -> Self {
+        Self {
+            template: None,
+            strip_dollar_prefix: false,
+            strip_fx_suffix: false,
+            anonymous: default_anonymous_name(),
+        }
+    }
+}
+
+/// How the path of the file is written to `loc.file`.
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum FilePath {
+    #[default]
+    Relative,
+    Absolute,
+    Basename,
+}
+
+#[derive(Debug, Clone)]
+pub struct FileName {
+    pub enabled: bool,
+    pub path: FilePath,
+    pub inline: bool,
+}
+
+impl Default for FileName {
+    fn default() -> Self {
+        Self { enabled: true, path: FilePath::default(), inline: false }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InputSourceMapOptions {
+    pub enabled: bool,
+    pub sids: bool,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -31,18 +147,43 @@ pub struct PublicConfig {
     #[serde(default)]
     pub add_loc: bool,
     #[serde(default)]
+    pub compact_loc: bool,
+    #[serde(default)]
     pub debug_sids: bool,
     #[serde(default)]
-    pub filename: bool,
+    pub add_pure_annotations: bool,
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_file_name")]
+    pub filename: FileName,
     #[serde(default)]
     pub no_defaults: bool,
     #[serde(default)]
     #[serde(deserialize_with = "deserialize_factories")]
-    pub factories: AHashSet<String>,
+    pub factories: AHashSet<ImportSelector>,
+    /// Replaces the built-in ecosystem factories, `None` keeps them.
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_builtin_factories")]
+    pub builtin_factories: Option<AHashSet<ImportSelector>>,
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_aliases")]
+    pub aliases: AHashMap<String, Vec<String>>,
     #[serde(default = "default_import_names")]
     #[serde(deserialize_with = "deserialize_import_names")]
     pub import_names: AHashSet<String>,
-    pub bindings: Option<Bindings>,
+    #[serde(default)]
+    pub bindings: Bindings,
+    #[serde(default)]
+    pub naming: Naming,
+    #[serde(default)]
+    pub derived_names: bool,
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_selectors")]
+    pub domains: AHashSet<ImportSelector>,
+    #[serde(default)]
+    pub with_factory_source: Option<String>,
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_input_source_map")]
+    pub input_source_map: InputSourceMapOptions,
 }
 
 #[derive(Debug, Clone)]
@@ -60,10 +201,24 @@ pub(crate) struct ReactMethods {
 
 #[derive(Debug, Clone, Default)]
 pub struct ImportViewNames {
-    pub scope: AHashSet<String>,
+    pub scope: Option<String>,
     pub no_scope: AHashSet<String>,
 }
 
+impl ImportViewNames {
+    fn new(scope: &str, no_scope: &[&str]) -> Self {
+        Self {
+            scope: Some(scope.to_owned()),
+            no_scope: no_scope.iter().map(|&source| source.to_owned()).collect(),
+        }
+    }
+
+    /// Whether `source` is any entry point of the library.
+    pub fn contains(&self, source: &str) -> bool {
+        self.no_scope.contains(source) || self.scope.as_deref() == Some(source)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct InternalConfig {
     pub stores: bool,
@@ -93,9 +248,14 @@ pub struct InternalConfig {
     pub split_creators: AHashSet<String>,
     pub api_creators: AHashSet<String>,
     pub merge_creators: AHashSet<String>,
-    pub import_react_names: ImportViewNames,
-    pub import_solid_names: ImportViewNames,
+    pub root_domains: AHashSet<ImportSelector>,
+    pub effector_sources: AHashSet<String>,
+    pub builtin_factories: AHashSet<ImportSelector>,
+    pub factory_invokers: AHashSet<ImportSelector>,
+    pub view_libraries: AHashMap<String, ImportViewNames>,
     pub(crate) domain_methods: DomainMethods,
+    pub(crate) derived_methods: AHashSet<String>,
+    pub(crate) unit_fields: AHashSet<String>,
     pub(crate) react_methods: ReactMethods,
 }
 
@@ -106,7 +266,23 @@ pub struct Config {
 }
 
 impl Config {
-    pub fn new(public: PublicConfig, internal: InternalConfig) -> Self {
+    pub fn new(public: PublicConfig, mut internal: InternalConfig) -> Self {
+        if let Some(builtin_factories) = &public.builtin_factories {
+            internal.builtin_factories = builtin_factories.clone();
+        }
+
+        for (name, binding) in &public.bindings {
+            let names = internal.view_libraries.entry(name.clone()).or_default();
+
+            if let Some(scope) = &binding.scope {
+                names.scope = Some(scope.clone());
+            }
+
+            if let Some(no_scope) = &binding.no_scope {
+                names.no_scope = no_scope.clone();
+            }
+        }
+
         Self { public, internal }
     }
 }
@@ -132,6 +308,36 @@ impl InternalConfig {
             effect: AHashSet::from_iter(["effect".into(), "createEffect".into()]),
             domain: AHashSet::from_iter(["domain".into(), "createDomain".into()]),
         };
+        let derived_methods = AHashSet::from_iter([
+            "map".into(),
+            "prepend".into(),
+            "filterMap".into(),
+            "filter".into(),
+        ]);
+        let unit_fields = AHashSet::from_iter([
+            "done".into(),
+            "doneData".into(),
+            "fail".into(),
+            "failData".into(),
+            "finally".into(),
+            "pending".into(),
+            "inFlight".into(),
+            "updates".into(),
+            "reinit".into(),
+        ]);
+        let effector_sources = AHashSet::from_iter([
+            "effector".into(),
+            "effector/compat".into(),
+            "effector-root".into(),
+            "effector-root/compat".into(),
+        ]);
+        let root_domains = AHashSet::from_iter([
+            ImportSelector::from("effector-root#root"),
+            ImportSelector::from("effector-root/compat#root"),
+        ]);
+        let builtin_factories = builtin_factories();
+        let factory_invokers =
+            AHashSet::from_iter([ImportSelector::from("@withease/factories#invoke")]);
         let react_methods =
             ReactMethods { create_gate: AHashSet::from_iter(["createGate".into()]) };
 
@@ -163,22 +369,28 @@ impl InternalConfig {
             split_creators,
             api_creators,
             merge_creators,
+            root_domains,
+            effector_sources,
+            builtin_factories,
+            factory_invokers,
             domain_methods,
+            derived_methods,
+            unit_fields,
             react_methods,
-            import_react_names: ImportViewNames {
-                scope: AHashSet::from_iter([
-                    "effector-react/scope".into(),
-                    "effector-react/ssr".into(),
-                ]),
-                no_scope: AHashSet::from_iter([
-                    "effector-react".into(),
-                    "effector-react/compat".into(),
-                ]),
-            },
-            import_solid_names: ImportViewNames {
-                scope: AHashSet::from_iter(["effector-solid/scope".into()]),
-                no_scope: AHashSet::from_iter(["effector-solid".into()]),
-            },
+            view_libraries: AHashMap::from_iter([
+                (
+                    "react".into(),
+                    ImportViewNames::new(
+                        "effector-react/scope",
+                        &["effector-react", "effector-react/compat"],
+                    ),
+                ),
+                ("solid".into(), ImportViewNames::new("effector-solid/scope", &["effector-solid"])),
+                (
+                    "vue".into(),
+                    ImportViewNames::new("effector-vue/ssr", &["effector-vue/composition"]),
+                ),
+            ]),
         }
     }
 }
@@ -187,6 +399,34 @@ fn r#true() -> bool {
     true
 }
 
+/// Operators and factories from ecosystem libraries, which create units on
+/// every call, so their units need unique sids per call site too.
+fn builtin_factories() -> AHashSet<ImportSelector> {
+    [
+        "patronum",
+        "patronum/*",
+        "@farfetched/core#createQuery",
+        "@farfetched/core#createJsonQuery",
+        "@farfetched/core#createHeadlessQuery",
+        "@farfetched/core#createMutation",
+        "@farfetched/core#createJsonMutation",
+        "@farfetched/core#createHeadlessMutation",
+        "@farfetched/core#createBarrier",
+        "atomic-router#createRoute",
+        "atomic-router#createRouter",
+        "atomic-router#createHistoryRouter",
+        "atomic-router#createRouterControls",
+        "atomic-router#chainRoute",
+    ]
+    .into_iter()
+    .map(ImportSelector::from)
+    .collect()
+}
+
+fn default_anonymous_name() -> String {
+    "inline_unit".into()
+}
+
 fn default_import_names() -> AHashSet<String> {
     AHashSet::from([
         "effector".into(),
@@ -204,6 +444,15 @@ fn default_import_names() -> AHashSet<String> {
     ])
 }
 
+fn deserialize_optional_import_names<'de, D>(
+    deserializer: D,
+) -> Result<Option<AHashSet<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_import_names(deserializer).map(Some)
+}
+
 fn deserialize_import_names<'de, D>(deserializer: D) -> Result<AHashSet<String>, D::Error>
 where
     D: Deserializer<'de>,
@@ -235,17 +484,190 @@ where
     deserializer.deserialize_any(StringOrHashSet)
 }
 
-fn deserialize_factories<'de, D>(deserializer: D) -> Result<AHashSet<String>, D::Error>
+fn deserialize_selectors<'de, D>(deserializer: D) -> Result<AHashSet<ImportSelector>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let res = deserialize_import_names(deserializer)?;
+
+    Ok(res.iter().map(|s| ImportSelector::from(s.as_str())).collect())
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AliasTargets {
+    One(String),
+    Many(Vec<String>),
+}
+
+fn deserialize_aliases<'de, D>(deserializer: D) -> Result<AHashMap<String, Vec<String>>, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let res: AHashSet<PathBuf> = AHashSet::deserialize(deserializer)?;
+    let res: AHashMap<String, AliasTargets> = AHashMap::deserialize(deserializer)?;
 
     Ok(res
         .into_iter()
-        .map(|mut p| {
-            p.set_extension("");
-            String::from(p.to_string_lossy())
+        .map(|(alias, targets)| match targets {
+            AliasTargets::One(target) => (alias, vec![target]),
+            AliasTargets::Many(targets) => (alias, targets),
         })
         .collect())
 }
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FileNameEntry {
+    Enabled(bool),
+    Options {
+        #[serde(default)]
+        path: FilePath,
+        #[serde(default)]
+        inline: bool,
+    },
+}
+
+fn deserialize_file_name<'de, D>(deserializer: D) -> Result<FileName, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match FileNameEntry::deserialize(deserializer)? {
+        FileNameEntry::Enabled(enabled) => FileName { enabled, ..FileName::default() },
+        FileNameEntry::Options { path, inline } => FileName { enabled: true, path, inline },
+    })
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum InputSourceMapEntry {
+    Enabled(bool),
+    Options {
+        #[serde(default)]
+        sids: bool,
+    },
+}
+
+fn deserialize_input_source_map<'de, D>(deserializer: D) -> Result<InputSourceMapOptions, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match InputSourceMapEntry::deserialize(deserializer)? {
+        InputSourceMapEntry::Enabled(enabled) => InputSourceMapOptions { enabled, sids: false },
+        InputSourceMapEntry::Options { sids } => InputSourceMapOptions { enabled: true, sids },
+    })
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BindingMethodEntry {
+    Name(String),
+    Options(BindingMethod),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BindingMethodsEntry {
+    Scope(Vec<String>),
+    Methods(AHashMap<String, BindingMethodEntry>),
+}
+
+/// A list of methods moves them to the `scope` entry point, while a map
+/// describes each method, e.g. `{"useStore": "useUnit"}`.
+fn deserialize_binding_methods<'de, D>(
+    deserializer: D,
+) -> Result<AHashMap<String, BindingMethod>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match BindingMethodsEntry::deserialize(deserializer)? {
+        BindingMethodsEntry::Scope(methods) => methods
+            .into_iter()
+            .map(|method| (method, BindingMethod { name: None, scope: true }))
+            .collect(),
+        BindingMethodsEntry::Methods(methods) => methods
+            .into_iter()
+            .map(|(method, entry)| {
+                let options = match entry {
+                    BindingMethodEntry::Name(name) => {
+                        BindingMethod { name: Some(name), scope: false }
+                    }
+                    BindingMethodEntry::Options(options) => options,
+                };
+
+                (method, options)
+            })
+            .collect(),
+    })
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FactoryEntry {
+    Selector(String),
+    Module { path: String, exports: Option<Vec<String>> },
+}
+
+fn deserialize_factories<'de, D>(deserializer: D) -> Result<AHashSet<ImportSelector>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let res: Vec<FactoryEntry> = Vec::deserialize(deserializer)?;
+
+    Ok(factory_selectors(res))
+}
+
+fn factory_selectors(entries: Vec<FactoryEntry>) -> AHashSet<ImportSelector> {
+    entries
+        .into_iter()
+        .flat_map(|entry| match entry {
+            FactoryEntry::Selector(selector) => vec![ImportSelector::from(selector.as_str())],
+            FactoryEntry::Module { path, exports: None } => {
+                vec![ImportSelector::from(path.as_str())]
+            }
+            FactoryEntry::Module { path, exports: Some(exports) } => exports
+                .iter()
+                .map(|export| ImportSelector::from(format!("{path}#{export}").as_str()))
+                .collect(),
+        })
+        .collect()
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BuiltinFactoriesEntry {
+    Enabled(bool),
+    Factories(Vec<FactoryEntry>),
+}
+
+fn deserialize_builtin_factories<'de, D>(
+    deserializer: D,
+) -> Result<Option<AHashSet<ImportSelector>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match BuiltinFactoriesEntry::deserialize(deserializer)? {
+        BuiltinFactoriesEntry::Enabled(true) => None,
+        BuiltinFactoriesEntry::Enabled(false) => Some(AHashSet::new()),
+        BuiltinFactoriesEntry::Factories(entries) => Some(factory_selectors(entries)),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::config::ImportSelector;
+
+    fn selector(source: &str, export: Option<&str>) -> ImportSelector {
+        ImportSelector { source: source.into(), export: export.map(String::from) }
+    }
+
+    #[test]
+    fn test_import_selector() {
+        assert_eq!(ImportSelector::from("patronum"), selector("patronum", None));
+        assert_eq!(ImportSelector::from("./model#createX"), selector("./model", Some("createX")));
+        assert_eq!(ImportSelector::from("#shared/factories"), selector("#shared/factories", None));
+        assert_eq!(
+            ImportSelector::from("#shared/factories#createToggle"),
+            selector("#shared/factories", Some("createToggle"))
+        );
+    }
+}
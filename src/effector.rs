@@ -2,26 +2,33 @@ use std::{
     cell::RefCell,
     collections::hash_map::Entry,
     fmt::{Debug, Formatter},
-    path::PathBuf,
+    path::Path,
     rc::Rc,
+    slice,
     sync::atomic::{AtomicUsize, Ordering},
 };
 
 use ahash::{AHashMap, AHashSet};
 use swc_core::{
-    common::{sync::Lrc, Loc, SourceMapper, DUMMY_SP},
+    common::{
+        comments::Comments, sync::Lrc, BytePos, CharPos, Loc, SourceMapper, Span, Spanned, DUMMY_SP,
+    },
     ecma::{
         ast::*,
+        atoms::JsWord,
         utils::{private_ident, quote_ident},
-        visit::{noop_visit_mut_type, VisitMut, VisitMutWith},
+        visit::{noop_visit_mut_type, noop_visit_type, Visit, VisitMut, VisitMutWith, VisitWith},
     },
 };
 
 use crate::{
-    config::Config,
+    config::{Config, FilePath, ImportSelector, Naming},
+    imports::{prologue_len, script_prologue_len, ImportManager},
+    naming::{format_name, NameParts, ANONYMOUS_NAME},
     obj_lit,
-    path::{normalize_path, strip_root},
+    path::{module_path, normalize_path, resolve_import_sources, strip_module_suffix, strip_root},
     sid::generate_stable_id,
+    source_map::InputSourceMap,
     PublicConfig,
 };
 
@@ -36,9 +43,14 @@ macro_rules! enclose {
 
 type MethodParserFun = Box<dyn Fn(&State, &Option<&str>)>;
 
+/// Derives a unit name from call arguments when there is no binding to take it
+/// from.
+type FallbackNameFun = fn(&[ExprOrSpread]) -> Option<String>;
+
 struct MethodParser {
     flag: bool,
     set: AHashSet<String>,
+    fallback_name: Option<FallbackNameFun>,
     fun: MethodParserFun,
 }
 
@@ -48,7 +60,12 @@ impl MethodParser {
         set: AHashSet<String>,
         fun: F,
     ) -> Self {
-        Self { flag, set, fun: Box::new(fun) }
+        Self { flag, set, fallback_name: None, fun: Box::new(fun) }
+    }
+
+    pub fn with_fallback_name(mut self, fallback_name: FallbackNameFun) -> Self {
+        self.fallback_name = Some(fallback_name);
+        self
     }
 }
 
@@ -63,6 +80,7 @@ type MethodParsers = Vec<MethodParser>;
 #[derive(Debug, Default)]
 struct UidGenerator {
     filename_count: AtomicUsize,
+    loc_count: AtomicUsize,
     factory_count: AtomicUsize,
 }
 
@@ -72,6 +90,11 @@ impl UidGenerator {
         private_ident!(format!("_effectorFileName${old}"))
     }
 
+    fn loc_generate_identifier(&self) -> Ident {
+        let old = self.loc_count.fetch_add(1, Ordering::Relaxed);
+        private_ident!(format!("_effectorLoc${old}"))
+    }
+
     fn factory_generate_identifier(&self, method: &str) -> Ident {
         let old = self.factory_count.fetch_add(1, Ordering::Relaxed);
         private_ident!(format!("_{method}${old}"))
@@ -79,15 +102,21 @@ impl UidGenerator {
 }
 
 #[derive(Debug, Clone)]
-struct SmallConfig {
+struct SmallConfig<'a> {
     add_loc: bool,
     add_names: bool,
     debug_sids: bool,
+    naming: &'a Naming,
 }
 
-impl From<&PublicConfig> for SmallConfig {
-    fn from(p: &PublicConfig) -> Self {
-        Self { add_loc: p.add_loc, add_names: p.add_names, debug_sids: p.debug_sids }
+impl<'a> From<&'a PublicConfig> for SmallConfig<'a> {
+    fn from(p: &'a PublicConfig) -> Self {
+        Self {
+            add_loc: p.add_loc,
+            add_names: p.add_names,
+            debug_sids: p.debug_sids,
+            naming: &p.naming,
+        }
     }
 }
 
@@ -98,45 +127,97 @@ fn property(key: &str, value: Expr) -> PropOrSpread {
     })))
 }
 
+/// Local binding of an import specifier along with the name it is exported
+/// under. Namespace imports have no single export, so they are skipped.
+fn imported_name(specifier: &ImportSpecifier) -> Option<(&Ident, JsWord)> {
+    match specifier {
+        ImportSpecifier::Default(default) => Some((&default.local, "default".into())),
+        ImportSpecifier::Named(named) => Some((
+            &named.local,
+            match &named.imported {
+                Some(ModuleExportName::Ident(ident)) => ident.sym.clone(),
+                Some(ModuleExportName::Str(str)) => str.value.clone(),
+                None => named.local.sym.clone(),
+            },
+        )),
+        ImportSpecifier::Namespace(_) => None,
+    }
+}
+
 fn make_trace(
-    filename_ident: &Option<Ident>,
+    file_name: &Option<Expr>,
     line_number: Option<usize>,
     column_number: Option<usize>,
-    uid_generator: &UidGenerator,
 ) -> Expr {
     let file_line_literal = Expr::from(line_number.unwrap_or(0));
 
     let file_column_literal = Expr::from(column_number.unwrap_or(0));
 
-    let file_prop = property(
-        "file",
-        Expr::Ident(
-            filename_ident.clone().unwrap_or_else(|| uid_generator.filename_generate_identifier()),
-        ),
-    );
+    let file_prop = file_name.clone().map(|file_name| property("file", file_name));
     let line_prop = property("line", file_line_literal);
     let column_prop = property("column", file_column_literal);
 
-    Expr::Object(ObjectLit { span: DUMMY_SP, props: vec![file_prop, line_prop, column_prop] })
+    Expr::Object(ObjectLit {
+        span: DUMMY_SP,
+        props: file_prop.into_iter().chain([line_prop, column_prop]).collect(),
+    })
+}
+
+/// With `compactLoc` each call site only passes its position to the helper
+/// declared once per file. Locations from other files of the input source map
+/// are still written out in full.
+fn state_make_trace(
+    state: &State<'_>,
+    line_number: Option<usize>,
+    column_number: Option<usize>,
+) -> Expr {
+    match (&state.loc_helper, &state.loc_file) {
+        (Some(helper), None) => Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: Callee::Expr(Box::new(Expr::Ident(helper.clone()))),
+            args: vec![
+                Expr::from(line_number.unwrap_or(0)).into(),
+                Expr::from(column_number.unwrap_or(0)).into(),
+            ],
+            type_args: None,
+        }),
+        _ => make_trace(state.trace_file(), line_number, column_number),
+    }
 }
 
 fn state_gen_stable_id(state: &State<'_>, name_node_id: &Option<&str>, debug_sids: bool) -> String {
+    let sid_name = state.sid_name.borrow();
+
     generate_stable_id(
         state.root.unwrap_or(""),
         state.filename.unwrap_or(""),
-        name_node_id,
-        state.loc.as_ref().unwrap().line as u32,
-        state.loc.as_ref().unwrap().col_display as u32,
+        &sid_name.as_deref().or(*name_node_id),
+        state.sid_loc.as_ref().or(state.loc.as_ref()).unwrap().line as u32,
+        state.sid_loc.as_ref().or(state.loc.as_ref()).unwrap().col_display as u32,
         debug_sids,
     )
 }
 
+fn state_display_name(state: &State<'_>, name: &str, kind: &str, naming: &Naming) -> String {
+    format_name(
+        naming,
+        &NameParts {
+            var: name,
+            kind,
+            root: state.root.unwrap_or(""),
+            filename: state.filename.unwrap_or(""),
+            line: state.loc.as_ref().map(|l| l.line).unwrap_or(0),
+        },
+    )
+}
+
 fn set_restore_name_after(
     state: &State<'_>,
     name_node_id: &Option<&str>,
     small_config: &SmallConfig,
+    kind: &str,
 ) {
-    let &SmallConfig { add_loc, add_names, debug_sids } = small_config;
+    let &SmallConfig { add_loc, add_names, debug_sids, naming } = small_config;
 
     let stable_id = state_gen_stable_id(state, name_node_id, debug_sids);
 
@@ -158,17 +239,17 @@ fn set_restore_name_after(
         let loc = state.loc.as_ref();
         let line = loc.map(|l| l.line);
         let column = loc.map(|l| l.col_display);
-        let loc_prop = property(
-            "loc",
-            make_trace(&state.file_name_identifier, line, column, &state.uid_generator),
-        );
+        let loc_prop = property("loc", state_make_trace(state, line, column));
 
         config_expr.props.push(loc_prop);
     }
 
     if let Some(display_name) = name_node_id {
         if add_names {
-            config_expr.props.push(property("name", Expr::from(*display_name)))
+            config_expr.props.push(property(
+                "name",
+                Expr::from(state_display_name(state, display_name, kind, naming)),
+            ))
         }
     }
 
@@ -185,10 +266,11 @@ fn set_config_for_conf_method(
     state: &State<'_>,
     name_node_id: &Option<&str>,
     small_config: &SmallConfig,
+    kind: &str,
     single_arg: bool,
     allow_empty_args: bool,
 ) {
-    let &SmallConfig { add_loc, add_names, debug_sids } = small_config;
+    let &SmallConfig { add_loc, add_names, debug_sids, naming } = small_config;
 
     let stable_id = state_gen_stable_id(state, name_node_id, debug_sids);
 
@@ -213,17 +295,17 @@ fn set_config_for_conf_method(
         let loc = state.loc.as_ref();
         let line = loc.map(|l| l.line);
         let column = loc.map(|l| l.col_display);
-        let loc_prop = property(
-            "loc",
-            make_trace(&state.file_name_identifier, line, column, &state.uid_generator),
-        );
+        let loc_prop = property("loc", state_make_trace(state, line, column));
 
         config_expr.props.push(loc_prop);
     }
 
     if let Some(display_name) = name_node_id {
         if add_names {
-            config_expr.props.push(property("name", Expr::from(*display_name)))
+            config_expr.props.push(property(
+                "name",
+                Expr::from(state_display_name(state, display_name, kind, naming)),
+            ))
         }
     }
 
@@ -240,8 +322,9 @@ fn set_event_name_after(
     state: &State<'_>,
     name_node_id: &Option<&str>,
     small_config: &SmallConfig,
+    kind: &str,
 ) {
-    let &SmallConfig { add_loc, add_names, debug_sids } = small_config;
+    let &SmallConfig { add_loc, add_names, debug_sids, naming } = small_config;
 
     let stable_id = state_gen_stable_id(state, name_node_id, debug_sids);
 
@@ -252,7 +335,15 @@ fn set_event_name_after(
 
     if first_argument.is_none() {
         if let Some(display_name) = name_node_id {
-            args.insert(0, ExprOrSpread::from(Expr::from(*display_name)))
+            args.insert(
+                0,
+                ExprOrSpread::from(Expr::from(state_display_name(
+                    state,
+                    display_name,
+                    kind,
+                    naming,
+                ))),
+            )
         }
     }
 
@@ -268,17 +359,17 @@ fn set_event_name_after(
         let loc = state.loc.as_ref();
         let line = loc.map(|l| l.line);
         let column = loc.map(|l| l.col_display);
-        let loc_prop = property(
-            "loc",
-            make_trace(&state.file_name_identifier, line, column, &state.uid_generator),
-        );
+        let loc_prop = property("loc", state_make_trace(state, line, column));
 
         config_expr.props.push(loc_prop);
     }
 
     if let Some(display_name) = name_node_id {
         if add_names {
-            config_expr.props.push(property("name", Expr::from(*display_name)))
+            config_expr.props.push(property(
+                "name",
+                Expr::from(state_display_name(state, display_name, kind, naming)),
+            ))
         }
     }
 
@@ -301,9 +392,10 @@ fn set_store_name_after(
     state: &State<'_>,
     name_node_id: &Option<&str>,
     small_config: &SmallConfig,
+    kind: &str,
     _fill_first_arg: bool,
 ) {
-    let &SmallConfig { add_loc, add_names, debug_sids } = small_config;
+    let &SmallConfig { add_loc, add_names, debug_sids, naming } = small_config;
 
     let stable_id = state_gen_stable_id(state, name_node_id, debug_sids);
 
@@ -320,17 +412,17 @@ fn set_store_name_after(
         let loc = state.loc.as_ref();
         let line = loc.map(|l| l.line);
         let column = loc.map(|l| l.col_display);
-        let loc_prop = property(
-            "loc",
-            make_trace(&state.file_name_identifier, line, column, &state.uid_generator),
-        );
+        let loc_prop = property("loc", state_make_trace(state, line, column));
 
         config_expr.props.push(loc_prop);
     }
 
     if let Some(display_name) = name_node_id {
         if add_names {
-            config_expr.props.push(property("name", Expr::from(*display_name)))
+            config_expr.props.push(property(
+                "name",
+                Expr::from(state_display_name(state, display_name, kind, naming)),
+            ))
         }
     }
 
@@ -343,54 +435,267 @@ fn set_store_name_after(
     }
 }
 
+/// Stores are named like `$user`, while `$` and `$$` alone are usually
+/// jQuery-like helpers.
+fn is_store_name(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    chars.next() == Some('$') && chars.next().is_some_and(|c| c == '_' || c.is_alphanumeric())
+}
+
+/// Name of the function used as an effect handler, either passed directly
+/// (`createEffect(async function fetchUser() {})`, `createEffect(fetchUser)`)
+/// or through the `handler` field of the config object.
+fn effect_handler_name(args: &[ExprOrSpread]) -> Option<String> {
+    fn handler_name(expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::Fn(FnExpr { ident: Some(ident), .. }) | Expr::Ident(ident) => {
+                Some(ident.sym.to_string())
+            }
+            _ => None,
+        }
+    }
+
+    let first_arg = args.get(0)?;
+
+    match &*first_arg.expr {
+        Expr::Object(obj) => obj.props.iter().find_map(|prop| match prop {
+            PropOrSpread::Prop(prop) => match &**prop {
+                Prop::KeyValue(KeyValueProp { key: PropName::Ident(key), value })
+                    if &*key.sym == "handler" =>
+                {
+                    handler_name(value)
+                }
+                _ => None,
+            },
+            _ => None,
+        }),
+        expr => handler_name(expr),
+    }
+}
+
+fn set_derived_name_after(
+    state: &State<'_>,
+    name_node_id: &Option<&str>,
+    small_config: &SmallConfig,
+    kind: &str,
+) {
+    let &SmallConfig { add_loc, add_names, naming, .. } = small_config;
+
+    let mut args = state.args.borrow_mut();
+
+    if args.is_empty() {
+        return;
+    }
+
+    let mut props = vec![];
+
+    if add_loc {
+        let loc = state.loc.as_ref();
+        let line = loc.map(|l| l.line);
+        let column = loc.map(|l| l.col_display);
+        let loc_prop = property("loc", state_make_trace(state, line, column));
+
+        props.push(loc_prop);
+    }
+
+    if let Some(display_name) = name_node_id {
+        if add_names {
+            props.push(property(
+                "name",
+                Expr::from(state_display_name(state, display_name, kind, naming)),
+            ))
+        }
+    }
+
+    if props.is_empty() {
+        return;
+    }
+
+    // `store.map(fn, { skipVoid })` and `event.filter({ fn })` already have
+    // a config object, so the metadata goes there
+    if let Some(Expr::Object(config)) = args.last_mut().map(|arg| &mut *arg.expr) {
+        config.props.extend(props);
+    } else {
+        args.push(ExprOrSpread::from(Expr::Object(ObjectLit { span: DUMMY_SP, props })));
+    }
+}
+
 fn apply_method_parsers(
     method_parsers: &MethodParsers,
     state: &State,
-    local: &str,
+    local: Option<&Id>,
     resolved: &str,
     id: &Option<&str>,
-) {
+) -> bool {
+    let mut applied = false;
+
     for method_parser in method_parsers {
-        let MethodParser { fun, flag, set } = method_parser;
+        let MethodParser { fun, flag, set, fallback_name } = method_parser;
 
         if *flag && set.contains(resolved) {
-            if state.is_local_ident(local) {
-                return;
+            if local.is_some_and(|local| state.is_local_ident(local)) {
+                return false;
             }
 
-            fun(state, &if let Some(id) = id { Some(id) } else { Some("inline_unit") })
+            // Keys of call configs, like `target` in `sample({ target })`, are
+            // not bindings, so the fallback is displayed instead of them
+            let fallback = match fallback_name {
+                Some(fallback_name) if id.is_none() || state.candidate_is_config_key => {
+                    fallback_name(&state.args.borrow())
+                }
+                _ => None,
+            };
+            let sid_name = id.unwrap_or(ANONYMOUS_NAME);
+
+            // The fallback only changes the name, sids are computed as before
+            *state.sid_name.borrow_mut() = fallback.is_some().then(|| sid_name.to_owned());
+            fun(state, &Some(fallback.as_deref().unwrap_or(sid_name)));
+            state.sid_name.take();
+            applied = true;
         }
     }
+
+    applied
+}
+
+/// Gives generated nodes the span of the call they were created for, so output
+/// source maps point them to the unit creation site. Syntax contexts are kept,
+/// since generated identifiers rely on them. Expressions with a span come from
+/// the source, so they are left as is along with everything inside them.
+struct SpanFiller(Span);
+
+impl VisitMut for SpanFiller {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        if expr.span().is_dummy() {
+            expr.visit_mut_children_with(self);
+        }
+    }
+
+    fn visit_mut_span(&mut self, span: &mut Span) {
+        if span.is_dummy() {
+            *span = Span { ctxt: span.ctxt, ..self.0 };
+        }
+    }
+}
+
+/// Collects every binding declared in the program. Relies on `resolver`, so
+/// bindings from different scopes never share an `Id`.
+#[derive(Debug, Default)]
+struct BindingCollector {
+    bindings: AHashSet<Id>,
+}
+
+impl Visit for BindingCollector {
+    noop_visit_type!();
+
+    fn visit_binding_ident(&mut self, i: &BindingIdent) {
+        self.bindings.insert(i.id.to_id());
+    }
+
+    fn visit_assign_pat_prop(&mut self, p: &AssignPatProp) {
+        self.bindings.insert(p.key.to_id());
+
+        p.visit_children_with(self);
+    }
+
+    fn visit_fn_decl(&mut self, f: &FnDecl) {
+        self.bindings.insert(f.ident.to_id());
+
+        f.visit_children_with(self);
+    }
+
+    fn visit_fn_expr(&mut self, f: &FnExpr) {
+        if let Some(ident) = &f.ident {
+            self.bindings.insert(ident.to_id());
+        }
+
+        f.visit_children_with(self);
+    }
+
+    fn visit_class_decl(&mut self, c: &ClassDecl) {
+        self.bindings.insert(c.ident.to_id());
+
+        c.visit_children_with(self);
+    }
+
+    fn visit_class_expr(&mut self, c: &ClassExpr) {
+        if let Some(ident) = &c.ident {
+            self.bindings.insert(ident.to_id());
+        }
+
+        c.visit_children_with(self);
+    }
 }
 
 #[derive(Debug, Default)]
 pub struct State<'a> {
-    file_name_identifier: Option<Ident>,
+    file_name: Option<Expr>,
     root: Option<&'a str>,
     filename: Option<&'a str>,
     args: RefCell<Vec<ExprOrSpread>>,
     loc: Option<Loc>,
+    /// Unmapped location used for sids, when `loc` comes from the input
+    /// source map and sids should not depend on it.
+    sid_loc: Option<Loc>,
+    /// Name used for sids, when the displayed name comes from elsewhere.
+    sid_name: RefCell<Option<String>>,
+    /// Whether the candidate name is a key of an object passed to a call,
+    /// rather than a binding.
+    candidate_is_config_key: bool,
+    /// File of `loc`, when it differs from the transformed one.
+    loc_file: Option<Expr>,
+    /// Per-file helper building `loc` objects, with `compactLoc`.
+    loc_helper: Option<Ident>,
     resolved_methods: AHashMap<Id, AHashSet<Id>>,
-    decls_visited: AHashSet<Decl>,
+    local_bindings: AHashSet<Id>,
     method_parsers: MethodParsers,
     domain_method_parsers: MethodParsers,
+    derived_method_parsers: MethodParsers,
     react_method_parsers: MethodParsers,
     uid_generator: UidGenerator,
 }
 
 impl<'a> State<'a> {
-    fn is_local_ident(&self, id: &str) -> bool {
-        self.decls_visited.iter().any(|d| match d {
-            Decl::Var(v) => v.decls.iter().any(|d| match &d.name {
-                Pat::Ident(ident) => ident.sym.as_ref() == id,
-                _ => false,
-            }),
-            Decl::Fn(f) => f.ident.sym.as_ref() == id,
-            _ => false,
-        })
+    /// Imported methods are never local, so any declaration with the same
+    /// `Id` shadows an effector method in its own scope only.
+    fn is_local_ident(&self, id: &Id) -> bool {
+        self.local_bindings.contains(id)
+    }
+
+    /// Resolves calls of `named.local` to the method it is imported as.
+    fn add_resolved_method(&mut self, named: &ImportNamedSpecifier) {
+        let local = named.local.clone();
+
+        let key = match &named.imported {
+            Some(ModuleExportName::Ident(ident)) => ident.clone(),
+            _ => local,
+        }
+        .to_id();
+
+        match self.resolved_methods.entry(key) {
+            Entry::Occupied(mut e) => {
+                let locals = e.get_mut();
+                locals.insert(named.local.to_id());
+            }
+            Entry::Vacant(v) => {
+                v.insert(AHashSet::from([named.local.to_id()]));
+            }
+        }
+    }
+
+    fn trace_file(&self) -> &Option<Expr> {
+        if self.loc_file.is_some() {
+            &self.loc_file
+        } else {
+            &self.file_name
+        }
     }
 
     pub fn new(config: Config, root: Option<&'a str>, filename: Option<&'a str>) -> Self {
+        let derived_names = config.public.derived_names;
         let public_rc = Rc::new(config.public);
 
         let method_parsers = vec![
@@ -399,7 +704,7 @@ impl<'a> State<'a> {
                 config.internal.store_creators,
                 enclose! { (public_rc) move |state, id| {
                     set_store_name_after(
-                        state, id, &SmallConfig::from(public_rc.as_ref()), false
+                        state, id, &SmallConfig::from(public_rc.as_ref()), "store", false
                     )
                 }},
             ),
@@ -407,28 +712,37 @@ impl<'a> State<'a> {
                 config.internal.events,
                 config.internal.event_creators,
                 enclose! { (public_rc) move |state, id| {
-                    set_event_name_after(state, id, &SmallConfig::from(public_rc.as_ref()))
+                    set_event_name_after(
+                        state, id, &SmallConfig::from(public_rc.as_ref()), "event"
+                    )
                 }},
             ),
             MethodParser::new(
                 config.internal.effects,
                 config.internal.effect_creators,
                 enclose! { (public_rc) move |state, id| {
-                    set_event_name_after(state, id, &SmallConfig::from(public_rc.as_ref()))
+                    set_event_name_after(
+                        state, id, &SmallConfig::from(public_rc.as_ref()), "effect"
+                    )
                 }},
-            ),
+            )
+            .with_fallback_name(effect_handler_name),
             MethodParser::new(
                 config.internal.domains,
                 config.internal.domain_creators,
                 enclose! { (public_rc) move |state, id| {
-                    set_event_name_after(state, id, &SmallConfig::from(public_rc.as_ref()))
+                    set_event_name_after(
+                        state, id, &SmallConfig::from(public_rc.as_ref()), "domain"
+                    )
                 }},
             ),
             MethodParser::new(
                 config.internal.restores,
                 config.internal.restore_creators,
                 enclose! { (public_rc) move |state, id| {
-                    set_restore_name_after(state, id, &SmallConfig::from(public_rc.as_ref()))
+                    set_restore_name_after(
+                        state, id, &SmallConfig::from(public_rc.as_ref()), "store"
+                    )
                 }},
             ),
             MethodParser::new(
@@ -436,7 +750,7 @@ impl<'a> State<'a> {
                 config.internal.combine_creators,
                 enclose! { (public_rc ) move |state, id| {
                     set_config_for_conf_method(
-                        state, id, &SmallConfig::from(public_rc.as_ref()), false, false
+                        state, id, &SmallConfig::from(public_rc.as_ref()), "store", false, false
                     )
                 }},
             ),
@@ -445,7 +759,7 @@ impl<'a> State<'a> {
                 config.internal.sample_creators,
                 enclose! { (public_rc ) move |state, id| {
                     set_config_for_conf_method(
-                        state, id, &SmallConfig::from(public_rc.as_ref()), false, false
+                        state, id, &SmallConfig::from(public_rc.as_ref()), "sample", false, false
                     )
                 }},
             ),
@@ -454,7 +768,7 @@ impl<'a> State<'a> {
                 config.internal.forward_creators,
                 enclose! { (public_rc ) move |state, id| {
                     set_config_for_conf_method(
-                        state, id, &SmallConfig::from(public_rc.as_ref()), true, false
+                        state, id, &SmallConfig::from(public_rc.as_ref()), "forward", true, false
                     )
                 }},
             ),
@@ -463,7 +777,7 @@ impl<'a> State<'a> {
                 config.internal.guard_creators,
                 enclose! { (public_rc ) move |state, id| {
                     set_config_for_conf_method(
-                        state, id, &SmallConfig::from(public_rc.as_ref()), false, false
+                        state, id, &SmallConfig::from(public_rc.as_ref()), "guard", false, false
                     )
                 }},
             ),
@@ -472,7 +786,7 @@ impl<'a> State<'a> {
                 config.internal.attach_creators,
                 enclose! { (public_rc ) move |state, id| {
                     set_config_for_conf_method(
-                        state, id, &SmallConfig::from(public_rc.as_ref()), true, false
+                        state, id, &SmallConfig::from(public_rc.as_ref()), "effect", true, false
                     )
                 }},
             ),
@@ -481,7 +795,7 @@ impl<'a> State<'a> {
                 config.internal.split_creators,
                 enclose! { (public_rc ) move |state, id| {
                     set_config_for_conf_method(
-                        state, id, &SmallConfig::from(public_rc.as_ref()), false, false
+                        state, id, &SmallConfig::from(public_rc.as_ref()), "split", false, false
                     )
                 }},
             ),
@@ -490,7 +804,7 @@ impl<'a> State<'a> {
                 config.internal.api_creators,
                 enclose! { (public_rc ) move |state, id| {
                     set_config_for_conf_method(
-                        state, id, &SmallConfig::from(public_rc.as_ref()), false, false
+                        state, id, &SmallConfig::from(public_rc.as_ref()), "api", false, false
                     )
                 }},
             ),
@@ -499,7 +813,7 @@ impl<'a> State<'a> {
                 config.internal.merge_creators,
                 enclose! { (public_rc) move |state, id| {
                     set_store_name_after(
-                        state, id, &SmallConfig::from(public_rc.as_ref()), false
+                        state, id, &SmallConfig::from(public_rc.as_ref()), "event", false
                     )
                 }},
             ),
@@ -511,7 +825,7 @@ impl<'a> State<'a> {
                 config.internal.domain_methods.store,
                 enclose! { (public_rc) move |state, id| {
                     set_store_name_after(
-                        state, id, &SmallConfig::from(public_rc.as_ref()), false
+                        state, id, &SmallConfig::from(public_rc.as_ref()), "store", false
                     )
                 }},
             ),
@@ -519,25 +833,42 @@ impl<'a> State<'a> {
                 config.internal.events,
                 config.internal.domain_methods.event,
                 enclose! { (public_rc) move |state, id| {
-                    set_event_name_after(state, id, &SmallConfig::from(public_rc.as_ref()))
+                    set_event_name_after(
+                        state, id, &SmallConfig::from(public_rc.as_ref()), "event"
+                    )
                 }},
             ),
             MethodParser::new(
                 config.internal.effects,
                 config.internal.domain_methods.effect,
                 enclose! { (public_rc) move |state, id| {
-                    set_event_name_after(state, id, &SmallConfig::from(public_rc.as_ref()))
+                    set_event_name_after(
+                        state, id, &SmallConfig::from(public_rc.as_ref()), "effect"
+                    )
                 }},
-            ),
+            )
+            .with_fallback_name(effect_handler_name),
             MethodParser::new(
                 config.internal.domains,
                 config.internal.domain_methods.domain,
                 enclose! { (public_rc) move |state, id| {
-                    set_event_name_after(state, id, &SmallConfig::from(public_rc.as_ref()))
+                    set_event_name_after(
+                        state, id, &SmallConfig::from(public_rc.as_ref()), "domain"
+                    )
                 }},
             ),
         ];
 
+        let derived_method_parsers = vec![MethodParser::new(
+            derived_names,
+            config.internal.derived_methods,
+            enclose! { (public_rc) move |state, id| {
+                set_derived_name_after(
+                    state, id, &SmallConfig::from(public_rc.as_ref()), "derived"
+                )
+            }},
+        )];
+
         let react_method_parsers = vec![MethodParser::new(
             config.internal.gates,
             config.internal.react_methods.create_gate,
@@ -546,6 +877,7 @@ impl<'a> State<'a> {
                     state,
                     id,
                     &SmallConfig::from(public_rc.as_ref()),
+                    "gate",
                     false,
                     true
                 )
@@ -553,38 +885,162 @@ impl<'a> State<'a> {
         )];
 
         Self {
-            file_name_identifier: None,
+            file_name: None,
             root,
             filename,
             method_parsers,
-            decls_visited: AHashSet::new(),
+            local_bindings: AHashSet::new(),
             loc: None,
+            sid_loc: None,
+            sid_name: RefCell::new(None),
+            candidate_is_config_key: false,
+            loc_file: None,
+            loc_helper: None,
             args: RefCell::new(vec![]),
             resolved_methods: AHashMap::new(),
             domain_method_parsers,
+            derived_method_parsers,
             react_method_parsers,
             uid_generator: UidGenerator::default(),
         }
     }
 }
 
+const FACTORY_ANNOTATION: &str = "@effector-factory";
+
+/// Functions declared at the top level of the module along with positions,
+/// where a factory annotation may be placed.
+fn module_functions(m: &Module) -> Vec<(&Ident, Vec<BytePos>)> {
+    let mut functions = vec![];
+
+    for item in &m.body {
+        match item {
+            ModuleItem::Stmt(Stmt::Decl(decl)) => decl_functions(decl, None, &mut functions),
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => {
+                decl_functions(&export.decl, Some(export.span.lo), &mut functions)
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                span,
+                decl: DefaultDecl::Fn(FnExpr { ident: Some(ident), function }),
+            })) => functions.push((ident, vec![span.lo, function.span.lo])),
+            _ => (),
+        }
+    }
+
+    functions
+}
+
+fn script_functions(s: &Script) -> Vec<(&Ident, Vec<BytePos>)> {
+    let mut functions = vec![];
+
+    for stmt in &s.body {
+        if let Stmt::Decl(decl) = stmt {
+            decl_functions(decl, None, &mut functions);
+        }
+    }
+
+    functions
+}
+
+fn decl_functions<'m>(
+    decl: &'m Decl,
+    export_pos: Option<BytePos>,
+    functions: &mut Vec<(&'m Ident, Vec<BytePos>)>,
+) {
+    match decl {
+        Decl::Fn(f) => {
+            functions.push((&f.ident, export_pos.into_iter().chain([f.function.span.lo]).collect()))
+        }
+        Decl::Var(var) => {
+            for d in &var.decls {
+                let (Pat::Ident(name), Some(init)) = (&d.name, &d.init) else {
+                    continue;
+                };
+
+                if matches!(&**init, Expr::Arrow(_) | Expr::Fn(_)) {
+                    let positions = export_pos.into_iter().chain([var.span.lo, init.span().lo]);
+                    functions.push((&name.id, positions.collect()));
+                }
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Source of an awaited dynamic import, e.g. `await import("./factory")`.
+fn dynamic_import_source(expr: &Expr) -> Option<String> {
+    let Expr::Await(AwaitExpr { arg, .. }) = expr else {
+        return None;
+    };
+
+    match &**arg {
+        Expr::Call(CallExpr { callee: Callee::Import(_), args, .. }) => {
+            match args.get(0).map(|arg| &*arg.expr) {
+                Some(Expr::Lit(Lit::Str(str))) => Some(str.value.to_string()),
+                Some(Expr::Tpl(tpl)) if tpl.exprs.is_empty() => {
+                    tpl.quasis.get(0).map(|quasi| quasi.raw.to_string())
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Name of the factory passed to `invoke` from `@withease/factories`.
+fn invoked_factory_name(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Ident(ident) => Some(ident.sym.to_string()),
+        Expr::Member(MemberExpr { prop: MemberProp::Ident(ident), .. }) => {
+            Some(ident.sym.to_string())
+        }
+        _ => None,
+    }
+}
+
 #[derive(Debug, Clone)]
 struct FactoryInfo {
     imported_name: String,
 }
 
+/// What a processed effector call returns.
+#[derive(Debug, Clone, Copy)]
+enum CallResult {
+    Domain,
+    Unit,
+    Other,
+}
+
 pub struct Effector<'a, C: SourceMapper> {
     config: Config,
     state: State<'a>,
-    ignored_imports: AHashSet<String>,
+    ignored_imports: AHashSet<Id>,
     candidate_name: Option<Ident>,
-    factory_paths: AHashSet<String>,
     factory_map: AHashMap<Id, FactoryInfo>,
+    factory_invokers: AHashSet<Id>,
+    factory_namespaces: AHashMap<Id, Vec<String>>,
+    /// Results of processed effector calls by call position.
+    call_results: AHashMap<BytePos, CallResult>,
+    unit_bindings: AHashSet<Id>,
+    domain_bindings: AHashSet<Id>,
+    /// Bindings known to hold something else than a domain.
+    non_domain_bindings: AHashSet<Id>,
+    /// Positions of object literals passed to calls, including the ones
+    /// replaced with generated configs.
+    config_objects: AHashSet<BytePos>,
+    /// Props being visited belong to an object literal passed to a call.
+    in_config_object: bool,
+    /// Positions of calls used as objects of member expressions, like
+    /// `createEvent()` in `createEvent().watch(fn)`.
+    chained_calls: AHashSet<BytePos>,
     need_factory_import: bool,
     factory_import_added: bool,
-    imports_to_add: AHashSet<ImportDecl>,
+    imports: ImportManager,
     with_factory_name: Option<Ident>,
+    effector_source: Option<JsWord>,
     cm: Lrc<C>,
+    comments: Option<Box<dyn Comments + 'a>>,
+    input_source_map: Option<InputSourceMap>,
 }
 
 impl<'a, C: SourceMapper> Effector<'a, C> {
@@ -599,151 +1055,671 @@ impl<'a, C: SourceMapper> Effector<'a, C> {
             state: State::new(config, root, filename),
             ignored_imports: AHashSet::new(),
             candidate_name: None,
-            factory_paths: AHashSet::new(),
             factory_map: AHashMap::new(),
+            factory_invokers: AHashSet::new(),
+            factory_namespaces: AHashMap::new(),
+            call_results: AHashMap::new(),
+            unit_bindings: AHashSet::new(),
+            domain_bindings: AHashSet::new(),
+            non_domain_bindings: AHashSet::new(),
+            config_objects: AHashSet::new(),
+            in_config_object: false,
+            chained_calls: AHashSet::new(),
             need_factory_import: false,
-            imports_to_add: AHashSet::new(),
+            imports: ImportManager::default(),
             with_factory_name: None,
+            effector_source: None,
             factory_import_added: false,
             cm: Lrc::new(cm),
+            comments: None,
+            input_source_map: None,
         }
     }
 
+    pub fn with_comments(mut self, comments: impl Comments + 'a) -> Self {
+        self.comments = Some(Box::new(comments));
+        self
+    }
+
+    /// Imports `method` from the configured `withFactorySource`, or from the
+    /// effector package the file already uses.
     fn add_import(&mut self, method: Ident) -> Ident {
-        let local_ident = self.state.uid_generator.factory_generate_identifier(method.as_ref());
-        let decl = ImportDecl {
-            span: DUMMY_SP,
-            src: Box::new(Str::from("effector")),
-            type_only: false,
-            asserts: None,
-            specifiers: vec![ImportSpecifier::Named(ImportNamedSpecifier {
-                span: DUMMY_SP,
-                local: local_ident.clone(),
-                imported: Some(ModuleExportName::Ident(method)),
-                is_type_only: false,
-            })],
+        let source = match &self.config.public.with_factory_source {
+            Some(source) => JsWord::from(source.as_str()),
+            None => self.effector_source.clone().unwrap_or_else(|| "effector".into()),
         };
+        let local_ident = self.state.uid_generator.factory_generate_identifier(method.as_ref());
 
-        self.imports_to_add.insert(decl);
+        self.imports.add(source, method, local_ident.clone());
 
         local_ident
     }
 
-    fn add_file_name_identifier(&mut self) -> Option<String> {
-        let mut filename_str = None;
-        if self.config.public.add_loc && self.state.file_name_identifier.is_none() {
-            filename_str = if self.state.filename.is_some() {
-                Some(strip_root(
-                    self.state.root.unwrap_or(""),
-                    self.state.filename.unwrap_or(""),
-                    false,
-                ))
-            } else {
-                None
-            };
+    /// Remembers the effector package the file imports from and an existing
+    /// `withFactory` import to reuse instead of adding a new one.
+    fn add_effector_import(&mut self, d: &ImportDecl) {
+        let source = &*d.src.value;
+        let is_effector = self.config.internal.effector_sources.contains(source);
+
+        if is_effector && self.effector_source.is_none() {
+            self.effector_source = Some(d.src.value.clone());
+        }
+
+        if d.type_only
+            || !(is_effector || self.config.public.with_factory_source.as_deref() == Some(source))
+        {
+            return;
+        }
+
+        for specifier in &d.specifiers {
+            if matches!(specifier, ImportSpecifier::Named(named) if named.is_type_only) {
+                continue;
+            }
+
+            if let Some((local, imported)) = imported_name(specifier) {
+                if &*imported == "withFactory" && !self.factory_import_added {
+                    self.factory_import_added = true;
+                    self.with_factory_name = Some(local.clone());
+                }
+            }
+        }
+    }
+
+    fn factory_selectors(&self) -> impl Iterator<Item = &ImportSelector> {
+        self.config.public.factories.iter().chain(self.config.internal.builtin_factories.iter())
+    }
+
+    fn is_factory_module(&self, sources: &[String]) -> bool {
+        let root = self.state.root.unwrap_or("");
+
+        self.factory_selectors()
+            .any(|factory| sources.iter().any(|s| factory.matches_module(root, s)))
+    }
+
+    fn is_factory_export(&self, sources: &[String], export: &str) -> bool {
+        let root = self.state.root.unwrap_or("");
+
+        self.factory_selectors()
+            .any(|factory| sources.iter().any(|s| factory.matches(root, s, export)))
+    }
+
+    /// Functions declared in the module itself are factories when the module
+    /// is listed in `factories` or when they are annotated with
+    /// `/* @effector-factory */`.
+    fn add_local_factories(&mut self, functions: Vec<(&Ident, Vec<BytePos>)>) {
+        let module = self.state.filename.map(|f| module_path(self.state.root.unwrap_or(""), f));
+
+        for (ident, positions) in functions {
+            let is_factory = positions.into_iter().any(|pos| self.has_factory_annotation(pos))
+                || module
+                    .as_ref()
+                    .is_some_and(|m| self.is_factory_export(slice::from_ref(m), &ident.sym));
+
+            if is_factory {
+                self.factory_map
+                    .insert(ident.to_id(), FactoryInfo { imported_name: ident.sym.to_string() });
+            }
+        }
+    }
+
+    fn has_factory_annotation(&self, pos: BytePos) -> bool {
+        self.comments
+            .as_ref()
+            .and_then(|comments| comments.get_leading(pos))
+            .is_some_and(|comments| comments.iter().any(|c| c.text.trim() == FACTORY_ANNOTATION))
+    }
+
+    /// Paths `source` may refer to. Built-in selectors only point to packages,
+    /// so it is resolved against the file only when the config may point to
+    /// local modules.
+    fn import_sources(&self, source: &str) -> Vec<String> {
+        let public = &self.config.public;
+        // Built-in factories are packages, while custom ones may be local paths
+        let has_custom_builtins = public.builtin_factories.as_ref().is_some_and(|f| !f.is_empty());
+
+        if public.factories.is_empty() && public.domains.is_empty() && !has_custom_builtins {
+            return vec![strip_module_suffix(source)];
+        }
+
+        resolve_import_sources(
+            self.state.root.unwrap_or(""),
+            self.state.filename.unwrap_or(""),
+            source,
+            &self.config.public.aliases,
+        )
+    }
+
+    /// Tracks factories loaded with `await import(...)`, either destructured
+    /// from the module or accessed as members of the whole module binding.
+    fn add_dynamic_import(&mut self, pat: &Pat, source: &str) {
+        if self.config.public.factories.is_empty()
+            && self.config.internal.builtin_factories.is_empty()
+        {
+            return;
+        }
+
+        let sources = self.import_sources(source);
+
+        match pat {
+            Pat::Ident(ident) => {
+                if self.is_factory_module(&sources) {
+                    self.factory_namespaces.insert(ident.id.to_id(), sources);
+                }
+            }
+            Pat::Object(obj) => {
+                for prop in &obj.props {
+                    let (export, local) = match prop {
+                        ObjectPatProp::KeyValue(KeyValuePatProp { key, value }) => {
+                            let export = match key {
+                                PropName::Ident(ident) => ident.sym.clone(),
+                                PropName::Str(str) => str.value.clone(),
+                                _ => continue,
+                            };
+
+                            match &**value {
+                                Pat::Ident(local) => (export, &local.id),
+                                _ => continue,
+                            }
+                        }
+                        ObjectPatProp::Assign(AssignPatProp { key, .. }) => (key.sym.clone(), key),
+                        ObjectPatProp::Rest(_) => continue,
+                    };
+
+                    if self.is_factory_export(&sources, &export) {
+                        self.factory_map.insert(
+                            local.to_id(),
+                            FactoryInfo { imported_name: export.to_string() },
+                        );
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Position and `method` of a factory called or constructed by `callee`,
+    /// which is either an imported factory or a member of a factory module.
+    fn factory_callee(&self, callee: &Expr) -> Option<(BytePos, String)> {
+        match callee {
+            Expr::Ident(ident) => self
+                .factory_map
+                .get(&ident.to_id())
+                .map(|info| (ident.span.lo, info.imported_name.clone())),
+            Expr::Member(MemberExpr { obj, prop: MemberProp::Ident(prop), .. }) => match &**obj {
+                Expr::Ident(obj) => self
+                    .factory_namespaces
+                    .get(&obj.to_id())
+                    .filter(|sources| self.is_factory_export(sources, &prop.sym))
+                    .map(|_| (prop.span.lo, prop.sym.to_string())),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Wraps a factory invocation into `withFactory({ sid, fn: () => fun })`,
+    /// so units created by every call get their own sids.
+    fn wrap_factory<N>(&mut self, mut fun: N, pos: BytePos, method: String) -> CallExpr
+    where
+        N: VisitMutWith<Self> + Into<Expr> + Spanned,
+    {
+        let span = fun.span();
+        let loc = self.set_loc(pos);
+        let candidate_name = self.candidate_name.clone();
+        let candidate_is_config_key = self.state.candidate_is_config_key;
+
+        if !self.factory_import_added {
+            self.factory_import_added = true;
+            self.with_factory_name = Some(self.add_import(quote_ident!("withFactory")));
+        }
+
+        let sid = state_gen_stable_id(
+            &self.state,
+            &candidate_name.as_ref().map(|i| i.as_ref()),
+            self.config.public.debug_sids,
+        );
+
+        self.state.args.borrow_mut().clear();
+        fun.visit_mut_children_with(self);
+
+        // Arguments of the factory may contain object literals and bindings,
+        // which overwrite the name of the unit being created.
+        self.candidate_name = candidate_name;
+        self.state.candidate_is_config_key = candidate_is_config_key;
+        self.set_loc(pos);
+
+        let mut call = swc_core::quote!(
+            "$factory({sid: $sid,fn:()=>$fun})" as Expr,
+            factory = self.with_factory_name.clone().unwrap(),
+            sid: Expr = sid.into(),
+            fun: Expr = fun.into(),
+        )
+        .expect_call();
+
+        if let Some(Expr::Object(obj)) = call.args.get_mut(0).map(|arg| &mut *arg.expr) {
+            if self.config.public.add_loc || self.config.public.add_names {
+                let name =
+                    self.candidate_name.as_ref().map(|n| n.sym.as_ref()).unwrap_or(ANONYMOUS_NAME);
+                let name_prop = property(
+                    "name",
+                    Expr::from(state_display_name(
+                        &self.state,
+                        name,
+                        "factory",
+                        &self.config.public.naming,
+                    )),
+                );
+                let method_prop = property("method", Expr::from(method));
+                obj.props.extend([name_prop, method_prop]);
+            }
+
+            if self.config.public.add_loc {
+                let loc_prop = property(
+                    "loc",
+                    state_make_trace(&self.state, Some(loc.line), Some(loc.col_display)),
+                );
+                obj.props.push(loc_prop);
+            }
+        }
+
+        call.visit_mut_with(&mut SpanFiller(span));
+        self.annotate_pure(span.lo);
+
+        call
+    }
+
+    /// Points the state to the unit created at `pos`. With `inputSourceMap`
+    /// the location is mapped back to the authored source.
+    fn set_loc(&mut self, pos: BytePos) -> Loc {
+        let loc = self.cm.lookup_char_pos(pos);
+        let options = self.config.public.input_source_map;
+
+        let original =
+            self.input_source_map.as_ref().and_then(|map| map.lookup(loc.line, loc.col.0));
+
+        let Some(original) = original else {
+            self.state.sid_loc = None;
+            self.state.loc_file = None;
+            self.state.loc = Some(loc.clone());
+
+            return loc;
+        };
+
+        let mapped = Loc {
+            line: original.line,
+            col: CharPos(original.column),
+            col_display: original.column,
+            ..loc.clone()
+        };
+
+        self.state.loc_file = original.source.and_then(|source| self.original_file_name(&source));
+        self.state.sid_loc = (!options.sids).then_some(loc);
+        self.state.loc = Some(mapped.clone());
+
+        mapped
+    }
+
+    /// Inline input source maps are referenced by a comment at the end of
+    /// the file, which is attached to its last token.
+    fn load_input_source_map(&mut self, end: BytePos) {
+        if !self.config.public.input_source_map.enabled {
+            return;
+        }
+
+        let Some(comments) = &self.comments else {
+            return;
+        };
+
+        let trailing = comments.get_trailing(end).unwrap_or_default();
+        let leading = comments.get_leading(end).unwrap_or_default();
+
+        self.input_source_map = trailing
+            .iter()
+            .chain(leading.iter())
+            .rev()
+            .find_map(|comment| InputSourceMap::from_comment(&comment.text));
+    }
+
+    /// `loc.file` for a source from the input source map. The transformed
+    /// file keeps using the shared file name.
+    fn original_file_name(&self, source: &str) -> Option<Expr> {
+        let filename = self.state.filename?;
+
+        if !self.config.public.filename.enabled {
+            return None;
+        }
+
+        let resolved = if source.contains("://") || Path::new(source).is_absolute() {
+            source.to_owned()
+        } else {
+            let dir = Path::new(filename).parent()?;
+
+            normalize_path(&dir.join(source)).to_string_lossy().into_owned()
+        };
+
+        (resolved != filename).then(|| Expr::from(self.display_file_name(&resolved)))
+    }
+
+    /// Remembers object literals passed as arguments, so their keys are not
+    /// taken for bindings. It runs before and after parsers, which move the
+    /// original arguments into generated configs.
+    fn add_config_objects(&mut self, args: &[ExprOrSpread]) {
+        for arg in args {
+            match &*arg.expr {
+                Expr::Object(obj) if !obj.span.is_dummy() => {
+                    self.config_objects.insert(obj.span.lo);
+                }
+                _ => (),
+            }
+        }
+    }
+
+    /// Remembers what the call at `pos` returns, so derived and domain
+    /// methods can be recognized on the binding it is assigned to. Methods
+    /// like `sample` or `createApi` return something else than a single unit.
+    fn add_call_result(&mut self, method: &str, pos: BytePos) {
+        let internal = &self.config.internal;
+        let domain_methods = &internal.domain_methods;
+
+        let result = if internal.domain_creators.contains(method)
+            || domain_methods.domain.contains(method)
+        {
+            CallResult::Domain
+        } else if [
+            &internal.store_creators,
+            &internal.event_creators,
+            &internal.effect_creators,
+            &internal.restore_creators,
+            &internal.combine_creators,
+            &internal.merge_creators,
+            &internal.derived_methods,
+            &domain_methods.store,
+            &domain_methods.event,
+            &domain_methods.effect,
+        ]
+        .iter()
+        .any(|methods| methods.contains(method))
+        {
+            CallResult::Unit
+        } else {
+            CallResult::Other
+        };
+
+        // Chained calls share the position, the outer one is visited first
+        self.call_results.entry(pos).or_insert(result);
+    }
+
+    /// Remembers what the declared binding holds. Results of calls the plugin
+    /// doesn't know may still be domains, like imports and parameters.
+    fn add_binding(&mut self, name: &Ident, init: &Expr) {
+        let result = match init {
+            Expr::Call(call) => match self.call_results.get(&call.span.lo) {
+                Some(result) => *result,
+                None => return,
+            },
+            Expr::Object(_)
+            | Expr::Array(_)
+            | Expr::Fn(_)
+            | Expr::Arrow(_)
+            | Expr::Class(_)
+            | Expr::Lit(_)
+            | Expr::Tpl(_) => CallResult::Other,
+            _ => return,
+        };
+
+        match result {
+            CallResult::Domain => {
+                self.domain_bindings.insert(name.to_id());
+            }
+            CallResult::Unit => {
+                self.unit_bindings.insert(name.to_id());
+                self.non_domain_bindings.insert(name.to_id());
+            }
+            CallResult::Other => {
+                self.non_domain_bindings.insert(name.to_id());
+            }
+        }
+    }
 
-            let filename_ident = self.state.uid_generator.filename_generate_identifier();
+    /// Marks the unit creator call at `pos` as side-effect free for
+    /// minifiers. Methods which link units together, like `sample`, are never
+    /// annotated.
+    fn annotate_pure_method(&self, method: &str, pos: BytePos) {
+        let internal = &self.config.internal;
+        let domain_methods = &internal.domain_methods;
+        let is_pure = [
+            &internal.store_creators,
+            &internal.event_creators,
+            &internal.effect_creators,
+            &internal.domain_creators,
+            &internal.restore_creators,
+            &internal.combine_creators,
+            &internal.attach_creators,
+            &internal.merge_creators,
+            &internal.derived_methods,
+            &domain_methods.store,
+            &domain_methods.event,
+            &domain_methods.effect,
+            &domain_methods.domain,
+        ]
+        .iter()
+        .any(|methods| methods.contains(method));
+
+        if is_pure {
+            self.annotate_pure(pos);
+        }
+    }
 
-            let _ = self.state.file_name_identifier.insert(filename_ident);
+    /// Annotations are placed at the start of the call, so a call chained
+    /// further shares it and would be marked pure as a whole.
+    fn annotate_pure(&self, pos: BytePos) {
+        if !self.config.public.add_pure_annotations || self.chained_calls.contains(&pos) {
+            return;
         }
 
-        filename_str
+        if let Some(comments) = &self.comments {
+            let is_pure = comments.get_leading(pos).is_some_and(|leading| {
+                leading.iter().any(|comment| comment.text.contains("__PURE__"))
+            });
+
+            if !is_pure {
+                comments.add_pure_comment(pos);
+            }
+        }
+    }
+
+    /// Derived methods share names with array methods, so they are only
+    /// processed on known units, stores named with `$` prefix and their unit
+    /// fields like `fx.doneData`.
+    fn is_unit(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Ident(ident) => {
+                is_store_name(&ident.sym) || self.unit_bindings.contains(&ident.to_id())
+            }
+            Expr::Member(MemberExpr { obj, prop: MemberProp::Ident(prop), .. }) => {
+                self.config.internal.unit_fields.contains(&*prop.sym) && self.is_unit(obj)
+            }
+            _ => false,
+        }
+    }
+
+    /// Domain methods are processed on any receiver, which is not known to
+    /// hold something else.
+    fn is_domain(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Ident(ident) => {
+                let id = ident.to_id();
+
+                self.domain_bindings.contains(&id) || !self.non_domain_bindings.contains(&id)
+            }
+            _ => true,
+        }
+    }
+
+    fn display_file_name(&self, filename: &str) -> String {
+        match self.config.public.filename.path {
+            FilePath::Relative => strip_root(self.state.root.unwrap_or(""), filename, false),
+            FilePath::Absolute => filename.to_owned(),
+            FilePath::Basename => Path::new(filename)
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Sets up `loc.file` according to the `filename` option. Unless it is
+    /// inlined, the file name is shared by all units of the file through a
+    /// variable, whose declaration is returned. With `compactLoc` the helper
+    /// holds the only copy, so no variable is needed.
+    fn file_name_decl(&mut self) -> Option<Stmt> {
+        let options = &self.config.public.filename;
+
+        if !self.config.public.add_loc || !options.enabled || self.state.file_name.is_some() {
+            return None;
+        }
+
+        let file_name = self.state.filename.map(|f| self.display_file_name(f)).unwrap_or_default();
+
+        if options.inline || self.config.public.compact_loc {
+            self.state.file_name = Some(Expr::from(file_name));
+            return None;
+        }
+
+        let file_name_ident = self.state.uid_generator.filename_generate_identifier();
+        self.state.file_name = Some(Expr::Ident(file_name_ident.clone()));
+
+        Some(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+            span: DUMMY_SP,
+            kind: VarDeclKind::Var,
+            declare: false,
+            decls: vec![VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(BindingIdent::from(file_name_ident)),
+                init: Some(Box::new(Expr::from(file_name))),
+                definite: false,
+            }],
+        }))))
+    }
+
+    /// Declares the helper, which builds `loc` objects for `compactLoc`. It is
+    /// a function declaration, so units created before it, e.g. above the
+    /// last import, can use it as well.
+    fn loc_helper_decl(&mut self) -> Option<Stmt> {
+        if !self.config.public.add_loc || !self.config.public.compact_loc {
+            return None;
+        }
+
+        let helper = self.state.uid_generator.loc_generate_identifier();
+        self.state.loc_helper = Some(helper.clone());
+
+        let file_prop = self.state.file_name.clone().map(|file_name| property("file", file_name));
+        let line_prop = property("line", Expr::Ident(quote_ident!("line")));
+        let column_prop = property("column", Expr::Ident(quote_ident!("column")));
+        let loc = Expr::Object(ObjectLit {
+            span: DUMMY_SP,
+            props: file_prop.into_iter().chain([line_prop, column_prop]).collect(),
+        });
+
+        Some(Stmt::Decl(Decl::Fn(FnDecl {
+            ident: helper,
+            declare: false,
+            function: Box::new(Function {
+                params: vec![
+                    Param::from(Pat::Ident(quote_ident!("line").into())),
+                    Param::from(Pat::Ident(quote_ident!("column").into())),
+                ],
+                decorators: vec![],
+                span: DUMMY_SP,
+                body: Some(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![Stmt::Return(ReturnStmt {
+                        span: DUMMY_SP,
+                        arg: Some(Box::new(loc)),
+                    })],
+                }),
+                is_generator: false,
+                is_async: false,
+                type_params: None,
+                return_type: None,
+            }),
+        })))
     }
 }
 
 impl<'a, C: SourceMapper> VisitMut for Effector<'a, C> {
     noop_visit_mut_type!();
 
-    fn visit_mut_decl(&mut self, d: &mut Decl) {
-        self.state.decls_visited.insert(d.clone());
+    fn visit_mut_member_expr(&mut self, e: &mut MemberExpr) {
+        if let Expr::Call(CallExpr { span, .. }) | Expr::New(NewExpr { span, .. }) = &*e.obj {
+            self.chained_calls.insert(span.lo);
+        }
 
-        d.visit_mut_children_with(self);
+        e.visit_mut_children_with(self);
     }
 
     fn visit_mut_member_prop(&mut self, p: &mut MemberProp) {
         self.candidate_name = if let MemberProp::Ident(id) = p { Some(id.clone()) } else { None };
+        self.state.candidate_is_config_key = false;
 
         p.visit_mut_children_with(self);
     }
 
     fn visit_mut_module(&mut self, m: &mut Module) {
-        let filename = self.add_file_name_identifier();
+        let mut collector = BindingCollector::default();
+        m.visit_with(&mut collector);
+        self.state.local_bindings = collector.bindings;
+        self.add_local_factories(module_functions(m));
+        self.load_input_source_map(m.span.hi);
+
+        let decls = self.file_name_decl().into_iter().chain(self.loc_helper_decl());
+        let index = m
+            .body
+            .iter()
+            .rposition(|m| matches!(m, ModuleItem::ModuleDecl(ModuleDecl::Import(_))))
+            .map_or(prologue_len(&m.body), |index| index + 1);
 
-        if let Some(file_name_ident) = &self.state.file_name_identifier {
-            if self.config.public.add_loc {
-                let last_import_index = m
-                    .body
-                    .iter()
-                    .rposition(|m| matches!(m, ModuleItem::ModuleDecl(ModuleDecl::Import(_))));
-                let stmt = ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
-                    span: DUMMY_SP,
-                    kind: VarDeclKind::Var,
-                    declare: false,
-                    decls: vec![VarDeclarator {
-                        span: DUMMY_SP,
-                        name: Pat::Ident(BindingIdent::from(file_name_ident.clone())),
-                        init: Some(Box::new(Expr::from(filename.unwrap_or_else(|| "".into())))),
-                        definite: false,
-                    }],
-                }))));
-                if let Some(index) = last_import_index {
-                    m.body.insert(index + 1, stmt);
-                } else {
-                    m.body.insert(0, stmt);
-                };
+        m.body.splice(index..index, decls.map(ModuleItem::Stmt).collect::<Vec<_>>());
+
+        // Imports are hoisted, so the one declared after a factory call is reused too
+        for item in &m.body {
+            if let ModuleItem::ModuleDecl(ModuleDecl::Import(d)) = item {
+                self.add_effector_import(d);
             }
         }
 
         m.visit_mut_children_with(self);
 
-        if !self.imports_to_add.is_empty() {
-            let last_import_index = m
-                .body
-                .iter()
-                .rposition(|i| matches!(i, ModuleItem::ModuleDecl(ModuleDecl::Import(_))));
-
-            if let Some(mut index) = last_import_index {
-                index += 1;
-                m.body.splice(
-                    index..index,
-                    self.imports_to_add
-                        .iter()
-                        .map(|i| ModuleItem::ModuleDecl(ModuleDecl::Import(i.clone()))),
-                );
-            }
-        }
+        self.imports.inject(m);
+    }
+
+    fn visit_mut_script(&mut self, s: &mut Script) {
+        let mut collector = BindingCollector::default();
+        s.visit_with(&mut collector);
+        self.state.local_bindings = collector.bindings;
+        self.add_local_factories(script_functions(s));
+        self.load_input_source_map(s.span.hi);
+
+        let decls = self.file_name_decl().into_iter().chain(self.loc_helper_decl());
+        let index = script_prologue_len(&s.body);
+
+        s.body.splice(index..index, decls.collect::<Vec<_>>());
+
+        s.visit_mut_children_with(self);
+
+        self.imports.inject_script(s);
     }
 
     fn visit_mut_import_decl(&mut self, d: &mut ImportDecl) {
         let source = &d.src.value.to_string();
-        let factories_used = !self.config.public.factories.is_empty();
-        let has_relative_factories = self
-            .config
-            .public
-            .factories
-            .iter()
-            .any(|f| f.starts_with("./") || f.starts_with("../"));
+        let factories_used = !self.config.public.factories.is_empty()
+            || !self.config.internal.builtin_factories.is_empty();
+        let root = self.state.root.unwrap_or("");
+        let normalized_sources = self.import_sources(source);
 
         if self.config.public.import_names.contains(&d.src.value.to_string()) {
             for specifier in &d.specifiers {
                 if let ImportSpecifier::Named(named) = specifier {
-                    let local = named.local.clone();
-
-                    let key = match &named.imported {
-                        Some(ModuleExportName::Ident(ident)) => ident.clone(),
-                        _ => local,
-                    }
-                    .to_id();
-
-                    match self.state.resolved_methods.entry(key) {
-                        Entry::Occupied(mut e) => {
-                            let locals = e.get_mut();
-                            locals.insert(named.local.to_id());
-                        }
-                        Entry::Vacant(v) => {
-                            v.insert(AHashSet::from([named.local.to_id()]));
-                        }
-                    }
+                    self.state.add_resolved_method(named);
                 }
             }
         } else {
@@ -752,137 +1728,193 @@ impl<'a, C: SourceMapper> VisitMut for Effector<'a, C> {
                     let local = named.local.sym.as_ref();
 
                     if self.state.method_parsers.iter().map(|m| &m.set).any(|s| s.contains(local)) {
-                        self.ignored_imports.insert(local.to_owned());
+                        self.ignored_imports.insert(named.local.to_id());
                     }
                 }
             }
         }
 
-        if let Some(bindings) = &self.config.public.bindings {
-            let mut check_and_replace =
-                |replace: bool, no_scope: &AHashSet<String>, scope: &AHashSet<String>| {
-                    if replace && no_scope.contains(source) {
-                        d.src = Box::new(
-                            scope.iter().find(|s| s.contains("scope")).unwrap().to_string().into(),
-                        )
-                    }
-                };
+        for specifier in &d.specifiers {
+            if let Some((local, imported)) = imported_name(specifier) {
+                let is_domain = self
+                    .config
+                    .internal
+                    .root_domains
+                    .iter()
+                    .chain(self.config.public.domains.iter())
+                    .any(|domain| {
+                        normalized_sources.iter().any(|s| domain.matches(root, s, &imported))
+                    });
+
+                if is_domain {
+                    self.domain_bindings.insert(local.to_id());
+                } else if !self.config.public.domains.is_empty() {
+                    // Only listed imports are domains, when the list is set
+                    self.non_domain_bindings.insert(local.to_id());
+                }
 
-            if let Some(react) = &bindings.react {
-                let import_react_names = &self.config.internal.import_react_names;
-                check_and_replace(
-                    react.scope_replace,
-                    &import_react_names.no_scope,
-                    &import_react_names.scope,
-                );
-            }
+                let is_invoker = self.config.internal.factory_invokers.iter().any(|invoker| {
+                    normalized_sources.iter().any(|s| invoker.matches(root, s, &imported))
+                });
 
-            if let Some(solid) = &bindings.solid {
-                let import_solid_names = &self.config.internal.import_solid_names;
-                check_and_replace(
-                    solid.scope_replace,
-                    &import_solid_names.no_scope,
-                    &import_solid_names.scope,
-                );
+                if is_invoker {
+                    self.need_factory_import = true;
+                    self.factory_invokers.insert(local.to_id());
+                }
             }
         }
 
-        if factories_used {
-            let root = self.state.root.unwrap_or("");
-
-            if self.factory_paths.is_empty() {
-                if has_relative_factories {
-                    self.factory_paths = self
-                        .config
-                        .public
-                        .factories
-                        .iter()
-                        .map(|fab| {
-                            if fab.starts_with("./") || fab.starts_with("../") {
-                                let resolved_fab =
-                                    normalize_path(&PathBuf::from(format!("{root}/{fab}")));
-                                strip_root(
-                                    root,
-                                    &String::from(resolved_fab.to_string_lossy()),
-                                    true,
-                                )
-                            } else {
-                                fab.clone()
-                            }
-                        })
-                        .collect()
-                } else {
-                    self.factory_paths = self.config.public.factories.clone();
+        let view_library = self
+            .config
+            .internal
+            .view_libraries
+            .iter()
+            .find(|(_, library)| library.contains(source));
+
+        if view_library.is_some() {
+            // Gates are tracked by their imported name, so aliased imports
+            // from any view library are detected too
+            for specifier in &d.specifiers {
+                let (ImportSpecifier::Named(named), Some((_, imported))) =
+                    (specifier, imported_name(specifier))
+                else {
+                    continue;
+                };
+
+                if self.config.internal.react_methods.create_gate.contains(&*imported) {
+                    self.state.add_resolved_method(named);
                 }
             }
+        }
+
+        if let Some((name, library)) = view_library {
+            let binding = self.config.public.bindings.get(name);
+            let scope_replace = binding.is_some_and(|binding| binding.scope_replace);
+            let no_scope = library.no_scope.contains(source);
 
-            let mut normalized_source: PathBuf = PathBuf::from(d.src.value.to_string());
+            if let Some(scope) = library.scope.as_ref().filter(|_| scope_replace && no_scope) {
+                d.src = Box::new(scope.as_str().into());
+            }
 
-            if normalized_source.starts_with(".") {
-                let current_file = self.state.filename.unwrap_or("");
+            if let Some(binding) = binding.filter(|_| !d.type_only) {
+                // Without `scopeReplace` only the listed methods are moved
+                let scope = library.scope.as_ref().filter(|_| no_scope && !scope_replace);
+                let mut scoped = vec![];
 
-                let path = PathBuf::from(current_file);
+                for specifier in std::mem::take(&mut d.specifiers) {
+                    let method = imported_name(&specifier)
+                        .and_then(|(_, imported)| binding.methods.get(&*imported));
 
-                let dir = path.parent().expect("Should have parent directory");
-                let resolved_import = normalize_path(&PathBuf::from(format!(
-                    "{dir}/{normalized_source}",
-                    dir = dir.display(),
-                    normalized_source = normalized_source.display()
-                )));
+                    match (specifier, method) {
+                        (ImportSpecifier::Named(mut named), Some(method)) => {
+                            if let Some(name) = &method.name {
+                                named.imported =
+                                    Some(ModuleExportName::Ident(quote_ident!(name.as_str())));
+                            }
 
-                normalized_source = PathBuf::from(strip_root(
-                    root,
-                    &String::from(resolved_import.to_string_lossy()),
-                    true,
-                ));
+                            if method.scope && scope.is_some() {
+                                scoped.push(named);
+                            } else {
+                                d.specifiers.push(ImportSpecifier::Named(named));
+                            }
+                        }
+                        (specifier, _) => d.specifiers.push(specifier),
+                    }
+                }
+
+                if let Some(scope) = scope.filter(|_| !scoped.is_empty()) {
+                    if d.specifiers.is_empty() {
+                        d.src = Box::new(scope.as_str().into());
+                        d.specifiers = scoped.into_iter().map(ImportSpecifier::Named).collect();
+                    } else {
+                        for named in scoped {
+                            let specifier = ImportSpecifier::Named(named);
+                            let Some((local, imported)) = imported_name(&specifier) else {
+                                continue;
+                            };
+
+                            self.imports.add(
+                                scope.as_str().into(),
+                                quote_ident!(imported),
+                                local.clone(),
+                            );
+                        }
+                    }
+                }
             }
+        }
 
-            normalized_source.set_extension("");
+        if factories_used {
+            for specifier in &d.specifiers {
+                // Whole factory modules may be imported to access factories as
+                // members, e.g. `factories.createCounter()`
+                let module_local = match specifier {
+                    ImportSpecifier::Namespace(namespace) => Some(&namespace.local),
+                    ImportSpecifier::Default(default) => Some(&default.local),
+                    ImportSpecifier::Named(_) => None,
+                };
 
-            if self.factory_paths.contains(&String::from(normalized_source.to_string_lossy())) {
-                self.need_factory_import = true;
+                if let Some(module_local) = module_local {
+                    if self.is_factory_module(&normalized_sources) {
+                        self.factory_namespaces
+                            .insert(module_local.to_id(), normalized_sources.clone());
+                    }
+                }
 
-                for specifier in &d.specifiers {
-                    let (local_name, imported_name) = match specifier {
-                        ImportSpecifier::Default(d) => (d.local.clone(), "default".to_string()),
-                        ImportSpecifier::Named(named) => (
-                            named.local.clone(),
-                            match &named.imported {
-                                Some(exported) => match exported {
-                                    ModuleExportName::Ident(id) => id.to_string(),
-                                    ModuleExportName::Str(str) => str.value.to_string(),
-                                },
-                                None => named.local.to_string(),
-                            },
-                        ),
-                        _ => continue,
-                    };
+                let Some((local, imported)) = imported_name(specifier) else {
+                    continue;
+                };
 
-                    self.factory_map.insert(local_name.to_id(), FactoryInfo { imported_name });
+                if !self.is_factory_export(&normalized_sources, &imported) {
+                    continue;
                 }
+
+                self.need_factory_import = true;
+
+                self.factory_map
+                    .insert(local.to_id(), FactoryInfo { imported_name: imported.to_string() });
             }
         }
 
         d.visit_mut_children_with(self);
     }
 
+    fn visit_mut_object_lit(&mut self, obj: &mut ObjectLit) {
+        let is_config = self.config_objects.contains(&obj.span.lo);
+
+        for prop in &mut obj.props {
+            self.in_config_object = is_config;
+            prop.visit_mut_with(self);
+        }
+    }
+
     fn visit_mut_key_value_prop(&mut self, p: &mut KeyValueProp) {
         self.candidate_name = match &p.key {
             PropName::Ident(id) => Some(id.clone()),
             _ => None,
         };
+        self.state.candidate_is_config_key = self.in_config_object;
 
         p.visit_mut_children_with(self);
     }
 
     fn visit_mut_var_declarator(&mut self, d: &mut VarDeclarator) {
+        if let Some(source) = d.init.as_deref().and_then(dynamic_import_source) {
+            self.add_dynamic_import(&d.name, &source);
+        }
+
         let ident = match &d.name {
             Pat::Ident(ident) => Some(ident.id.clone()),
             _ => None,
         };
 
         self.candidate_name = ident;
+        self.state.candidate_is_config_key = false;
         d.visit_mut_children_with(self);
+
+        if let (Pat::Ident(name), Some(init)) = (&d.name, &d.init) {
+            self.add_binding(&name.id, init);
+        }
     }
 
     fn visit_mut_assign_expr(&mut self, e: &mut AssignExpr) {
@@ -898,10 +1930,31 @@ impl<'a, C: SourceMapper> VisitMut for Effector<'a, C> {
         };
 
         self.candidate_name = ident;
+        self.state.candidate_is_config_key = false;
+        e.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        if let Expr::New(new) = e {
+            if let Some((pos, method)) = self.factory_callee(&new.callee) {
+                *e = Expr::Call(self.wrap_factory(new.clone(), pos, method));
+                return;
+            }
+        }
+
         e.visit_mut_children_with(self);
     }
 
     fn visit_mut_call_expr(&mut self, e: &mut CallExpr) {
+        let call_pos = e.span.lo;
+        self.add_config_objects(&e.args);
+        // Whether a config object was added to the arguments
+        let mut applied = false;
+        let factory = match &e.callee {
+            Callee::Expr(callee) => self.factory_callee(callee),
+            _ => None,
+        };
+
         if let Callee::Expr(expr) = &mut e.callee {
             match &mut **expr {
                 Expr::Member(member) => {
@@ -909,137 +1962,121 @@ impl<'a, C: SourceMapper> VisitMut for Effector<'a, C> {
                         let local = ident.sym.to_string();
                         self.state.args = RefCell::new(e.args.clone());
 
-                        let loc = self.cm.lookup_char_pos(ident.span.lo);
-                        self.state.loc = Some(loc);
+                        self.set_loc(ident.span.lo);
 
-                        apply_method_parsers(
-                            &self.state.domain_method_parsers,
-                            &self.state,
-                            "",
-                            &local,
-                            &self.candidate_name.as_ref().map(|i| i.as_ref()),
-                        );
+                        let mut created_unit = self.is_domain(&member.obj)
+                            && apply_method_parsers(
+                                &self.state.domain_method_parsers,
+                                &self.state,
+                                None,
+                                &local,
+                                &self.candidate_name.as_ref().map(|i| i.as_ref()),
+                            );
+
+                        if self.is_unit(&member.obj) {
+                            created_unit |= apply_method_parsers(
+                                &self.state.derived_method_parsers,
+                                &self.state,
+                                None,
+                                &local,
+                                &self.candidate_name.as_ref().map(|i| i.as_ref()),
+                            );
+                        }
+
+                        if created_unit {
+                            applied = true;
+                            self.add_call_result(&local, call_pos);
+                            self.annotate_pure_method(&local, call_pos);
+                        }
+
+                        if let Some((pos, method)) = factory {
+                            *e = self.wrap_factory(e.clone(), pos, method);
+                            return;
+                        }
                     }
                 }
                 Expr::Ident(ident) => {
                     let local = ident.sym.to_string();
+                    let local_id = ident.to_id();
+                    let pos = ident.span.lo;
 
-                    if !self.ignored_imports.contains(&local) {
+                    if !self.ignored_imports.contains(&local_id) {
                         let resolved =
                             self.state.resolved_methods.iter().find_map(|(k, locals)| {
                                 locals.contains(&ident.to_id()).then_some(k)
                             });
                         if let Some(resolved) = resolved {
-                            let loc = self.cm.lookup_char_pos(ident.span.lo);
-
-                            self.state.loc = Some(loc);
+                            // `set_loc` needs the whole state, so the method
+                            // can't stay borrowed from `resolved_methods`
+                            let resolved_name = resolved.0.clone();
+                            self.set_loc(ident.span.lo);
                             self.state.args = RefCell::new(e.args.clone());
-                            apply_method_parsers(
+                            let created_unit = apply_method_parsers(
                                 &self.state.method_parsers,
                                 &self.state,
-                                &local,
-                                resolved.0.as_ref(),
+                                Some(&local_id),
+                                resolved_name.as_ref(),
                                 &self.candidate_name.as_ref().map(|i| i.as_ref()),
                             );
-                            apply_method_parsers(
+                            applied |= created_unit;
+                            applied |= apply_method_parsers(
                                 &self.state.react_method_parsers,
                                 &self.state,
-                                &local,
-                                resolved.0.as_ref(),
+                                Some(&local_id),
+                                resolved_name.as_ref(),
                                 &self.candidate_name.as_ref().map(|i| i.as_ref()),
                             );
+
+                            if created_unit {
+                                self.add_call_result(&resolved_name, call_pos);
+                                self.annotate_pure_method(&resolved_name, call_pos);
+                            }
                         }
                     } else {
                         return;
                     }
 
                     if self.state.resolved_methods.is_empty() {
-                        let loc = self.cm.lookup_char_pos(ident.span.lo);
-
-                        self.state.loc = Some(loc);
+                        self.set_loc(ident.span.lo);
                         self.state.args = RefCell::new(e.args.clone());
-                        apply_method_parsers(
+                        let created_unit = apply_method_parsers(
                             &self.state.method_parsers,
                             &self.state,
-                            &local,
+                            Some(&local_id),
                             &local,
                             &self.candidate_name.as_ref().map(|i| i.as_ref()),
                         );
-                        apply_method_parsers(
+                        applied |= created_unit;
+                        applied |= apply_method_parsers(
                             &self.state.react_method_parsers,
                             &self.state,
-                            &local,
+                            Some(&local_id),
                             &local,
                             &self.candidate_name.as_ref().map(|i| i.as_ref()),
                         );
-                    }
-
-                    if let Some(FactoryInfo { imported_name }) = self.factory_map.get(&ident.to_id()).cloned()
-                    {
-                        let loc = self.cm.lookup_char_pos(ident.span.lo);
 
-                        self.state.loc = Some(loc.clone());
-                        self.state.args = RefCell::new(e.args.clone());
-                        if !self.factory_import_added {
-                            self.factory_import_added = true;
-                            self.with_factory_name =
-                                Some(self.add_import(quote_ident!("withFactory")));
+                        if created_unit {
+                            self.add_call_result(&local, call_pos);
+                            self.annotate_pure_method(&local, call_pos);
                         }
+                    }
 
-                        let sid = generate_stable_id(
-                            self.state.root.unwrap_or(""),
-                            self.state.filename.unwrap_or(""),
-                            &self.candidate_name.as_ref().map(|i| i.as_ref()),
-                            loc.line as u32,
-                            loc.col_display as u32,
-                            self.config.public.debug_sids,
-                        );
-
-                        let mut e_cloned = e.clone();
-                        e_cloned.visit_mut_children_with(self);
-
-                        let expr = swc_core::quote!(
-                            "$factory({sid: $sid,fn:()=>$fun})" as Expr,
-                            factory = self.with_factory_name.clone().unwrap(),
-                            sid: Expr = sid.into(),
-                            fun: Expr = Expr::Call(e_cloned),
-                        );
+                    let method = if let Some((_, method)) = factory {
+                        Some(method)
+                    } else if self.factory_invokers.contains(&local_id) {
+                        Some(
+                            e.args
+                                .get(0)
+                                .and_then(|arg| invoked_factory_name(&arg.expr))
+                                .unwrap_or(local),
+                        )
+                    } else {
+                        None
+                    };
 
-                        if let Expr::Call(mut call) = expr {
-                            if let Some(arg) = call.args.get_mut(0) {
-                                if let Expr::Object(obj) = &mut *arg.expr {
-                                    if self.config.public.add_loc || self.config.public.add_names {
-                                        let name_prop = property(
-                                            "name",
-                                            Expr::from(
-                                                self.candidate_name
-                                                    .clone()
-                                                    .map(|n| n.sym)
-                                                    .unwrap_or_else(|| "inline_unit".into()),
-                                            ),
-                                        );
-                                        let method_prop =
-                                            property("method", Expr::from(imported_name));
-                                        obj.props.extend([name_prop, method_prop]);
-                                    }
-
-                                    if self.config.public.add_loc {
-                                        let loc_prop = property(
-                                            "loc",
-                                            make_trace(
-                                                &self.state.file_name_identifier,
-                                                Some(loc.line),
-                                                Some(loc.col_display),
-                                                &self.state.uid_generator,
-                                            ),
-                                        );
-                                        obj.props.push(loc_prop);
-                                    }
-                                }
-                            }
-                            *e = call;
-                            return;
-                        }
+                    if let Some(method) = method {
+                        *e = self.wrap_factory(e.clone(), pos, method);
+                        return;
                     }
                 }
                 _ => (),
@@ -1049,10 +2086,15 @@ impl<'a, C: SourceMapper> VisitMut for Effector<'a, C> {
         let mut args = self.state.args.borrow_mut();
         if !args.is_empty() {
             e.args = args.drain(..).collect();
+
+            if applied {
+                e.args.iter_mut().for_each(|arg| arg.visit_mut_with(&mut SpanFiller(e.span)));
+            }
         }
 
         drop(args);
 
+        self.add_config_objects(&e.args);
         e.visit_mut_children_with(self);
     }
 }
@@ -0,0 +1,137 @@
+use swc_core::{
+    common::DUMMY_SP,
+    ecma::{ast::*, atoms::JsWord, utils::quote_ident},
+};
+
+/// Imports of runtime helpers like `withFactory`, which are added to the
+/// program once it is transformed.
+#[derive(Default)]
+pub struct ImportManager {
+    imports: Vec<(JsWord, Vec<(Ident, Ident)>)>,
+}
+
+impl ImportManager {
+    /// Requests `imported` as `local` from `source`. Sources and their
+    /// specifiers are emitted in the order they were requested.
+    pub fn add(&mut self, source: JsWord, imported: Ident, local: Ident) {
+        match self.imports.iter_mut().find(|(s, _)| *s == source) {
+            Some((_, specifiers)) => specifiers.push((imported, local)),
+            None => self.imports.push((source, vec![(imported, local)])),
+        }
+    }
+
+    /// Merges requested imports into existing declarations from the same
+    /// source, the rest is inserted after the last import of the module or
+    /// after its directives.
+    pub fn inject(&mut self, m: &mut Module) {
+        let mut new_imports = vec![];
+
+        for (source, specifiers) in self.imports.drain(..) {
+            let specifiers = specifiers.into_iter().map(|(imported, local)| {
+                ImportSpecifier::Named(ImportNamedSpecifier {
+                    span: DUMMY_SP,
+                    imported: (imported.sym != local.sym)
+                        .then_some(ModuleExportName::Ident(imported)),
+                    local,
+                    is_type_only: false,
+                })
+            });
+
+            let existing = m.body.iter_mut().find_map(|item| match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(decl)) if can_merge(decl, &source) => {
+                    Some(decl)
+                }
+                _ => None,
+            });
+
+            match existing {
+                Some(decl) => decl.specifiers.extend(specifiers),
+                None => new_imports.push(ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                    span: DUMMY_SP,
+                    specifiers: specifiers.collect(),
+                    src: Box::new(Str::from(source)),
+                    type_only: false,
+                    asserts: None,
+                }))),
+            }
+        }
+
+        let index = m
+            .body
+            .iter()
+            .rposition(|item| matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(_))))
+            .map_or(prologue_len(&m.body), |index| index + 1);
+
+        m.body.splice(index..index, new_imports);
+    }
+
+    /// Scripts can't have imports, so helpers are required instead, e.g.
+    /// `var { withFactory: _withFactory$0 } = require("effector")`.
+    pub fn inject_script(&mut self, s: &mut Script) {
+        let requires = self.imports.drain(..).map(|(source, specifiers)| {
+            let props = specifiers
+                .into_iter()
+                .map(|(imported, local)| {
+                    ObjectPatProp::KeyValue(KeyValuePatProp {
+                        key: PropName::Ident(imported),
+                        value: Box::new(Pat::Ident(BindingIdent::from(local))),
+                    })
+                })
+                .collect();
+
+            Stmt::Decl(Decl::Var(Box::new(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Var,
+                declare: false,
+                decls: vec![VarDeclarator {
+                    span: DUMMY_SP,
+                    name: Pat::Object(ObjectPat {
+                        span: DUMMY_SP,
+                        props,
+                        optional: false,
+                        type_ann: None,
+                    }),
+                    init: Some(Box::new(Expr::Call(CallExpr {
+                        span: DUMMY_SP,
+                        callee: Callee::Expr(Box::new(Expr::Ident(quote_ident!("require")))),
+                        args: vec![ExprOrSpread {
+                            spread: None,
+                            expr: Box::new(Expr::Lit(Lit::Str(Str::from(source)))),
+                        }],
+                        type_args: None,
+                    }))),
+                    definite: false,
+                }],
+            })))
+        });
+
+        let index = script_prologue_len(&s.body);
+
+        s.body.splice(index..index, requires.collect::<Vec<_>>());
+    }
+}
+
+/// Named specifiers can't be added to type-only imports, imports with
+/// assertions and namespace imports.
+fn can_merge(decl: &ImportDecl, source: &JsWord) -> bool {
+    decl.src.value == *source
+        && !decl.type_only
+        && decl.asserts.is_none()
+        && !decl.specifiers.iter().any(|s| matches!(s, ImportSpecifier::Namespace(_)))
+}
+
+/// Directives like `"use client"` only work at the very top of the module, so
+/// nothing may be inserted before them.
+pub fn is_directive(stmt: &Stmt) -> bool {
+    matches!(stmt, Stmt::Expr(ExprStmt { expr, .. }) if matches!(&**expr, Expr::Lit(Lit::Str(_))))
+}
+
+pub fn prologue_len(body: &[ModuleItem]) -> usize {
+    body.iter()
+        .take_while(|item| matches!(item, ModuleItem::Stmt(stmt) if is_directive(stmt)))
+        .count()
+}
+
+pub fn script_prologue_len(body: &[Stmt]) -> usize {
+    body.iter().take_while(|stmt| is_directive(stmt)).count()
+}
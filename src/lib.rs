@@ -1,8 +1,11 @@
 mod config;
 mod effector;
+mod imports;
 mod macros;
+mod naming;
 mod path;
 mod sid;
+mod source_map;
 
 use swc_core::{
     ecma::{ast::Program, visit::VisitMutWith},
@@ -35,6 +38,10 @@ pub fn effector(mut program: Program, data: TransformPluginProgramMetadata) -> P
 
     let mut plugin = Effector::new(config, root.as_deref(), filename.as_deref(), data.source_map);
 
+    if let Some(comments) = data.comments {
+        plugin = plugin.with_comments(comments);
+    }
+
     program.visit_mut_with(&mut plugin);
 
     program
@@ -0,0 +1,98 @@
+use std::path::Path;
+
+use crate::{config::Naming, path::strip_root};
+
+/// Name used for units which are not bound to any variable. It is also a part
+/// of their sid, so it must not depend on the naming config.
+pub const ANONYMOUS_NAME: &str = "inline_unit";
+
+pub struct NameParts<'a> {
+    pub var: &'a str,
+    pub kind: &'a str,
+    pub root: &'a str,
+    pub filename: &'a str,
+    pub line: usize,
+}
+
+fn transform_var<'a>(naming: &'a Naming, var: &'a str) -> &'a str {
+    if var == ANONYMOUS_NAME {
+        return &naming.anonymous;
+    }
+
+    let mut transformed = var;
+
+    if naming.strip_dollar_prefix {
+        transformed = transformed.strip_prefix('$').unwrap_or(transformed);
+    }
+
+    if naming.strip_fx_suffix {
+        transformed = transformed.strip_suffix("Fx").unwrap_or(transformed);
+    }
+
+    if transformed.is_empty() {
+        var
+    } else {
+        transformed
+    }
+}
+
+pub fn format_name(naming: &Naming, parts: &NameParts<'_>) -> String {
+    let var = transform_var(naming, parts.var);
+
+    let Some(template) = &naming.template else {
+        return var.to_owned();
+    };
+
+    let relative = strip_root(parts.root, parts.filename, true);
+    let path = Path::new(&relative);
+    let file = path.file_stem().map(|f| f.to_string_lossy()).unwrap_or_default();
+    let dir = path.parent().map(|d| d.to_string_lossy()).unwrap_or_default();
+
+    template
+        .replace("{file}", &file)
+        .replace("{dir}", &dir)
+        .replace("{var}", var)
+        .replace("{kind}", parts.kind)
+        .replace("{line}", &parts.line.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        config::Naming,
+        naming::{format_name, NameParts, ANONYMOUS_NAME},
+    };
+
+    fn parts<'a>(var: &'a str, kind: &'a str) -> NameParts<'a> {
+        NameParts { var, kind, root: "/app", filename: "/app/src/models/user.ts", line: 12 }
+    }
+
+    #[test]
+    fn test_raw_name() {
+        assert_eq!(format_name(&Naming::default(), &parts("$user", "store")), "$user");
+    }
+
+    #[test]
+    fn test_template() {
+        let naming = Naming {
+            template: Some("{dir}/{file}:{kind}:{var}@{line}".into()),
+            strip_dollar_prefix: true,
+            strip_fx_suffix: true,
+            ..Naming::default()
+        };
+
+        assert_eq!(format_name(&naming, &parts("$user", "store")), "src/models/user:store:user@12");
+        assert_eq!(
+            format_name(&naming, &parts("fetchUserFx", "effect")),
+            "src/models/user:effect:fetchUser@12"
+        );
+        assert_eq!(format_name(&naming, &parts("Fx", "effect")), "src/models/user:effect:Fx@12");
+    }
+
+    #[test]
+    fn test_anonymous() {
+        let naming = Naming { anonymous: "anonymous".into(), ..Naming::default() };
+
+        assert_eq!(format_name(&naming, &parts(ANONYMOUS_NAME, "event")), "anonymous");
+    }
+}
@@ -1,5 +1,7 @@
 use std::path::{Component, Path, PathBuf};
 
+use ahash::AHashMap;
+
 pub fn normalize_path(path: &Path) -> PathBuf {
     let mut components = path.components().peekable();
     let mut ret = if let Some(c @ Component::Prefix(..)) = components.peek().cloned() {
@@ -40,3 +42,139 @@ pub fn strip_root(babel_root: &str, filename: &str, omit_first_slash: bool) -> S
 
     normalized_seq.join("/")
 }
+
+fn is_relative(path: &str) -> bool {
+    path.starts_with("./") || path.starts_with("../")
+}
+
+/// Resolves a relative path from the plugin config against the project root.
+pub fn resolve_config_path(root: &str, path: &str) -> String {
+    if !is_relative(path) {
+        return path.to_owned();
+    }
+
+    let resolved = normalize_path(&PathBuf::from(format!("{root}/{path}")));
+
+    strip_root(root, &resolved.to_string_lossy(), true)
+}
+
+pub fn is_glob(pattern: &str) -> bool {
+    pattern.contains(['*', '?'])
+}
+
+fn match_segment(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((b'*', rest)) => (0..=text.len()).any(|i| match_segment(rest, &text[i..])),
+        Some((b'?', rest)) => !text.is_empty() && match_segment(rest, &text[1..]),
+        Some((c, rest)) => text.first() == Some(c) && match_segment(rest, &text[1..]),
+    }
+}
+
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|i| match_segments(rest, &path[i..])),
+        Some((segment, rest)) => match path.split_first() {
+            Some((first, path_rest)) => {
+                match_segment(segment.as_bytes(), first.as_bytes())
+                    && match_segments(rest, path_rest)
+            }
+            None => false,
+        },
+    }
+}
+
+/// Matches `/`-separated path against a glob, where `*` and `?` stay within a
+/// single segment and `**` matches any number of segments.
+pub fn glob_match(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.split('/').collect::<Vec<_>>();
+    let path = path.split('/').collect::<Vec<_>>();
+
+    match_segments(&pattern, &path)
+}
+
+const MODULE_EXTENSIONS: [&str; 8] = ["js", "jsx", "mjs", "cjs", "ts", "tsx", "mts", "cts"];
+
+/// Drops script extension and `/index` suffix, so `./model`, `./model.mjs`
+/// and `./model/index.ts` all refer to the same module.
+pub fn strip_module_suffix(path: &str) -> String {
+    let path = match path.rsplit_once('.') {
+        Some((base, ext)) if MODULE_EXTENSIONS.contains(&ext) => base,
+        _ => path,
+    };
+
+    path.strip_suffix("/index").unwrap_or(path).to_owned()
+}
+
+/// Resolves a relative import source against the importing file, so it can be
+/// compared with paths from the config.
+pub fn resolve_import_source(root: &str, filename: &str, source: &str) -> String {
+    if !is_relative(source) {
+        return strip_module_suffix(source);
+    }
+
+    // Relative sources can't be resolved without the importing file, so they
+    // are kept as is and never match paths from the config
+    let Some(dir) = Path::new(filename).parent() else {
+        return strip_module_suffix(source);
+    };
+    let resolved = normalize_path(&dir.join(source));
+
+    module_path(root, &resolved.to_string_lossy())
+}
+
+/// Project-relative path of a module file in the same form as resolved import
+/// sources, so the current file can be matched against the config too.
+pub fn module_path(root: &str, filename: &str) -> String {
+    strip_module_suffix(&strip_root(root, filename, true))
+}
+
+/// Same as `resolve_import_source`, but also returns every path the source
+/// maps to through aliases, which follow tsconfig `paths` format.
+pub fn resolve_import_sources(
+    root: &str,
+    filename: &str,
+    source: &str,
+    aliases: &AHashMap<String, Vec<String>>,
+) -> Vec<String> {
+    let mut sources = vec![resolve_import_source(root, filename, source)];
+
+    for (alias, targets) in aliases {
+        let rest = match alias.strip_suffix('*') {
+            Some(prefix) => source.strip_prefix(prefix),
+            None => (source == alias).then_some(""),
+        };
+
+        if let Some(rest) = rest {
+            sources.extend(targets.iter().map(|target| {
+                strip_module_suffix(&resolve_config_path(root, &target.replacen('*', rest, 1)))
+            }));
+        }
+    }
+
+    sources
+}
+
+#[cfg(test)]
+mod test {
+    use crate::path::{glob_match, resolve_import_source};
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("src/shared/factories/**", "src/shared/factories/toggle"));
+        assert!(glob_match("src/shared/factories/**", "src/shared/factories/form/field"));
+        assert!(glob_match("@company/models/*", "@company/models/user"));
+        assert!(!glob_match("@company/models/*", "@company/models/user/list"));
+        assert!(glob_match("src/**/*.factory", "src/features/user/user.factory"));
+        assert!(glob_match("src/model?", "src/model2"));
+        assert!(!glob_match("src/shared/factories/**", "src/shared/utils"));
+    }
+
+    #[test]
+    fn test_resolve_import_source() {
+        assert_eq!(resolve_import_source("/app", "/app/src/index.ts", "./model.ts"), "src/model");
+        assert_eq!(resolve_import_source("/app", "/app/src/index.ts", "patronum"), "patronum");
+        assert_eq!(resolve_import_source("/app", "", "./model"), "./model");
+    }
+}
@@ -0,0 +1,75 @@
+use sourcemap::{decode_data_url, DecodedMap};
+
+/// Position in the authored source, which the input file was generated from.
+pub struct OriginalLoc {
+    /// 1-based, like `Loc::line`
+    pub line: usize,
+    pub column: usize,
+    pub source: Option<String>,
+}
+
+/// Source map of the input file, when it is a result of another tool. Only
+/// inline maps are supported, since the plugin has no access to other files.
+pub struct InputSourceMap {
+    map: DecodedMap,
+}
+
+impl InputSourceMap {
+    /// Reads the map from a `sourceMappingURL` comment. The source text is not
+    /// available to wasm plugins, while comments are.
+    pub fn from_comment(text: &str) -> Option<Self> {
+        let url = text
+            .trim()
+            .strip_prefix("# sourceMappingURL=")
+            .or_else(|| text.trim().strip_prefix("@ sourceMappingURL="))?;
+
+        decode_data_url(url.trim()).ok().map(|map| Self { map })
+    }
+
+    /// Only tokens on the same generated line are used, a preceding token
+    /// from another line would point to unrelated code.
+    pub fn lookup(&self, line: usize, column: usize) -> Option<OriginalLoc> {
+        let line = line.checked_sub(1)? as u32;
+        let token = self.map.lookup_token(line, column as u32)?;
+
+        if token.get_dst_line() != line {
+            return None;
+        }
+
+        Some(OriginalLoc {
+            line: token.get_src_line() as usize + 1,
+            column: token.get_src_col() as usize,
+            source: token.get_source().map(String::from),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::source_map::InputSourceMap;
+
+    // `original.ts` with a single mapping from 2:14 to 10:4
+    const COMMENT: &str = "# sourceMappingURL=data:application/json;base64,eyJ2ZXJzaW9uIjozLCJzb3VyY2VzIjpbIm9yaWdpbmFsLnRzIl0sIm5hbWVzIjpbXSwibWFwcGluZ3MiOiI7Y0FTSSJ9";
+
+    #[test]
+    fn test_lookup() {
+        let map = InputSourceMap::from_comment(COMMENT).unwrap();
+        let original = map.lookup(2, 14).unwrap();
+
+        assert_eq!((original.line, original.column), (10, 4));
+        assert_eq!(original.source.as_deref(), Some("original.ts"));
+    }
+
+    #[test]
+    fn test_lookup_other_line() {
+        let map = InputSourceMap::from_comment(COMMENT).unwrap();
+
+        assert!(map.lookup(3, 14).is_none());
+        assert!(map.lookup(1, 0).is_none());
+    }
+
+    #[test]
+    fn test_not_a_source_map() {
+        assert!(InputSourceMap::from_comment(" @effector-factory ").is_none());
+    }
+}
@@ -2,7 +2,7 @@ use std::{fs::read_to_string, path::PathBuf};
 
 use effector_swc_plugin::{Config, Effector, InternalConfig, PublicConfig};
 use swc_core::{
-    common::{chain, FilePathMapping, Mark, SourceMap},
+    common::{chain, comments::SingleThreadedComments, FilePathMapping, Mark, SourceMap},
     ecma::{
         transforms::testing::{test_fixture, FixtureTestConfig},
         visit::{as_folder, Fold},
@@ -13,8 +13,14 @@ use swc_ecmascript::{
     transforms::resolver,
 };
 
-fn effector<'a>(config: Config, root: &'a str, filename: &'a str, cm: SourceMap) -> impl Fold + 'a {
-    as_folder(Effector::new(config, Some(root), Some(filename), cm))
+fn effector<'a>(
+    config: Config,
+    root: &'a str,
+    filename: &'a str,
+    cm: SourceMap,
+    comments: SingleThreadedComments,
+) -> impl Fold + 'a {
+    as_folder(Effector::new(config, Some(root), Some(filename), cm).with_comments(comments))
 }
 
 #[testing::fixture("tests/fixtures/**/code.js")]
@@ -26,7 +32,7 @@ fn fixture(input: PathBuf) {
 
     test_fixture(
         Syntax::Es(EsConfig { jsx: true, ..Default::default() }),
-        &|_t| {
+        &|t| {
             let cm = SourceMap::new(FilePathMapping::empty());
 
             cm.load_file(&input).unwrap();
@@ -40,7 +46,7 @@ fn fixture(input: PathBuf) {
 
             chain!(
                 resolver(Mark::new(), Mark::new(), false),
-                effector(config, &root, &filename, cm)
+                effector(config, &root, &filename, cm, (*t.comments).clone())
             )
         },
         &input,
@@ -0,0 +1,9 @@
+import { createStore } from "effector";
+import { createGate, useUnit } from "effector-vue/composition";
+import { useView } from "@app/view";
+
+const $count = createStore(0);
+const PageGate = createGate("Page");
+
+const count = useUnit($count);
+const view = useView();
@@ -0,0 +1,18 @@
+import { createStore } from "effector";
+import { createGate, useUnit } from "effector-vue/ssr";
+import { useView } from "@app/view/scope";
+const $count = createStore(0, {
+    sid: "295wawkygxs77",
+    name: "$count"
+});
+const PageGate = createGate({
+    and: [
+        "Page"
+    ],
+    or: {
+        sid: "70co8giknuej",
+        name: "PageGate"
+    }
+});
+const count = useUnit($count);
+const view = useView();
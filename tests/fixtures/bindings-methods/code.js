@@ -0,0 +1,8 @@
+import { createEvent, createStore } from "effector";
+import { useStore, useEvent as useHandler, useList } from "effector-react";
+
+const $items = createStore([]);
+const clear = createEvent();
+
+const items = useStore($items);
+const onClear = useHandler(clear);
@@ -0,0 +1,13 @@
+import { createEvent, createStore } from "effector";
+import { useUnit as useStore, useUnit as useHandler } from "effector-react";
+import { useList } from "effector-react/scope";
+const $items = createStore([], {
+    sid: "1h0kbqgjtwhlw",
+    name: "$items"
+});
+const clear = createEvent({
+    sid: "1480y65imj9dy",
+    name: "clear"
+});
+const items = useStore($items);
+const onClear = useHandler(clear);
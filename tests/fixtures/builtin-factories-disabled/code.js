@@ -0,0 +1,5 @@
+import { createEvent } from "effector";
+import { debounce } from "patronum";
+
+const changed = createEvent();
+const debounced = debounce({ source: changed, timeout: 100 });
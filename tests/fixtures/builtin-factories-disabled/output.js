@@ -0,0 +1,10 @@
+import { createEvent } from "effector";
+import { debounce } from "patronum";
+const changed = createEvent({
+    sid: "pka4gwosxzsx",
+    name: "changed"
+});
+const debounced = debounce({
+    source: changed,
+    timeout: 100
+});
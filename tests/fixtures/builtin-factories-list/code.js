@@ -0,0 +1,7 @@
+import { createEvent } from "effector";
+import { debounce } from "patronum";
+import { createCounter } from "../shared/counter";
+
+const changed = createEvent();
+const debounced = debounce({ source: changed, timeout: 100 });
+const counter = createCounter();
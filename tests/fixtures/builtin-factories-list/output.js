@@ -0,0 +1,17 @@
+import { createEvent, withFactory as _withFactory$0 } from "effector";
+import { debounce } from "patronum";
+import { createCounter } from "../shared/counter";
+const changed = createEvent({
+    sid: "2sn8w5u0lhfay",
+    name: "changed"
+});
+const debounced = debounce({
+    source: changed,
+    timeout: 100
+});
+const counter = _withFactory$0({
+    sid: "y6bv45zayilt",
+    fn: ()=>createCounter(),
+    name: "counter",
+    method: "createCounter"
+});
@@ -0,0 +1,4 @@
+import { createStore } from 'effector';
+const $early = createStore(0);
+import { createEvent } from 'effector';
+const reset = createEvent();
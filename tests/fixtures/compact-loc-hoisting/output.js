@@ -0,0 +1,19 @@
+import { createStore } from 'effector';
+const $early = createStore(0, {
+    sid: "oadczl2rw1cr",
+    loc: _effectorLoc$0(2, 15),
+    name: "$early"
+});
+import { createEvent } from 'effector';
+function _effectorLoc$0(line, column) {
+    return {
+        file: "/output.js",
+        line: line,
+        column: column
+    };
+}
+const reset = createEvent({
+    sid: "18mo5sgaekwaw",
+    loc: _effectorLoc$0(4, 14),
+    name: "reset"
+});
@@ -0,0 +1,3 @@
+import { createEvent, createStore } from 'effector';
+const $demo = createStore(0);
+const reset = createEvent();
@@ -0,0 +1,18 @@
+import { createEvent, createStore } from 'effector';
+function _effectorLoc$0(line, column) {
+    return {
+        file: "/output.js",
+        line: line,
+        column: column
+    };
+}
+const $demo = createStore(0, {
+    sid: "27smw5if358nn",
+    loc: _effectorLoc$0(2, 14),
+    name: "$demo"
+});
+const reset = createEvent({
+    sid: "2a1g0cv9llvxd",
+    loc: _effectorLoc$0(3, 14),
+    name: "reset"
+});
@@ -0,0 +1,5 @@
+import $ from "jquery";
+var _effectorFileName$0 = "/output.js";
+const ids = $("#id").map((index, element)=>element.id);
+const names = $.map(list, (item)=>item.name);
+const visible = $$.filter((element)=>element.visible);
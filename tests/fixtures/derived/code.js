@@ -0,0 +1,15 @@
+import { createStore, createEvent, createEffect } from "effector";
+
+const $user = createStore({ name: "" });
+const submit = createEvent();
+const fetchFx = createEffect();
+
+const $fullName = $user.map((user) => user.name);
+const submitted = submit.prepend((e) => e);
+const valid = submitted.filterMap((e) => e);
+const done = submit.filter({ fn: Boolean });
+const $result = fetchFx.doneData.map((result) => result, { skipVoid: false });
+const names = list.map((item) => item.name);
+const $tags = createStore([]);
+const tagNames = $tags.defaultState.map((tag) => tag.name);
+const tagsChanged = $tags.updates.filter({ fn: Boolean });
@@ -0,0 +1,93 @@
+import { createStore, createEvent, createEffect } from "effector";
+var _effectorFileName$0 = "/output.js";
+const $user = createStore({
+    name: ""
+}, {
+    sid: "3o6ame2db3vf4",
+    loc: {
+        file: _effectorFileName$0,
+        line: 3,
+        column: 14
+    },
+    name: "$user"
+});
+const submit = createEvent({
+    sid: "2407a18zwsujd",
+    loc: {
+        file: _effectorFileName$0,
+        line: 4,
+        column: 15
+    },
+    name: "submit"
+});
+const fetchFx = createEffect({
+    sid: "qs5ap2o1z20n",
+    loc: {
+        file: _effectorFileName$0,
+        line: 5,
+        column: 16
+    },
+    name: "fetchFx"
+});
+const $fullName = $user.map((user)=>user.name, {
+    loc: {
+        file: _effectorFileName$0,
+        line: 7,
+        column: 24
+    },
+    name: "$fullName"
+});
+const submitted = submit.prepend((e)=>e, {
+    loc: {
+        file: _effectorFileName$0,
+        line: 8,
+        column: 25
+    },
+    name: "submitted"
+});
+const valid = submitted.filterMap((e)=>e, {
+    loc: {
+        file: _effectorFileName$0,
+        line: 9,
+        column: 24
+    },
+    name: "valid"
+});
+const done = submit.filter({
+    fn: Boolean,
+    loc: {
+        file: _effectorFileName$0,
+        line: 10,
+        column: 20
+    },
+    name: "done"
+});
+const $result = fetchFx.doneData.map((result)=>result, {
+    skipVoid: false,
+    loc: {
+        file: _effectorFileName$0,
+        line: 11,
+        column: 33
+    },
+    name: "$result"
+});
+const names = list.map((item)=>item.name);
+const $tags = createStore([], {
+    sid: "kbujoenck6u3",
+    loc: {
+        file: _effectorFileName$0,
+        line: 13,
+        column: 14
+    },
+    name: "$tags"
+});
+const tagNames = $tags.defaultState.map((tag)=>tag.name);
+const tagsChanged = $tags.updates.filter({
+    fn: Boolean,
+    loc: {
+        file: _effectorFileName$0,
+        line: 15,
+        column: 34
+    },
+    name: "tagsChanged"
+});
@@ -0,0 +1,9 @@
+"use client";
+
+/* @effector-factory */
+function createToggle() {
+  const $on = createStore(false);
+  return $on;
+}
+
+const toggle = createToggle();
@@ -0,0 +1,27 @@
+"use client";
+import { withFactory as _withFactory$0 } from "effector";
+var _effectorFileName$0 = "/output.js";
+/* @effector-factory */
+function createToggle() {
+    const $on = createStore(false, {
+        sid: "2l26adw2w9sih",
+        loc: {
+            file: _effectorFileName$0,
+            line: 5,
+            column: 14
+        },
+        name: "$on"
+    });
+    return $on;
+}
+const toggle = _withFactory$0({
+    sid: "63ss1ds80nqg",
+    fn: ()=>createToggle(),
+    name: "toggle",
+    method: "createToggle",
+    loc: {
+        file: _effectorFileName$0,
+        line: 9,
+        column: 15
+    }
+});
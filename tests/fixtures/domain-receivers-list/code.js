@@ -0,0 +1,8 @@
+import { shared, api } from "./shared";
+
+const $sharedStore = shared.createStore(0);
+const click = api.event("click");
+
+function createModel(domain) {
+  return domain.createEvent();
+}
@@ -0,0 +1,12 @@
+import { shared, api } from "./shared";
+const $sharedStore = shared.createStore(0, {
+    sid: "2h01j4xb0ytv3",
+    name: "$sharedStore"
+});
+const click = api.event("click");
+function createModel(domain) {
+    return domain.createEvent({
+        sid: "3funw8583xadb",
+        name: "event"
+    });
+}
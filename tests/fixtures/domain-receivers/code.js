@@ -0,0 +1,18 @@
+import { createDomain } from "effector";
+import { root } from "effector-root";
+import { shared } from "./shared";
+
+const app = createDomain();
+const $store = app.store(0);
+const inner = app.createDomain();
+const event = inner.event();
+const $rootStore = root.createStore(0);
+const $sharedStore = shared.createStore(0);
+
+function createModel(domain) {
+  return domain.createEvent();
+}
+
+const analytics = { event(name) {} };
+createDomain();
+const click = analytics.event("click");
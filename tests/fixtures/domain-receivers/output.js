@@ -0,0 +1,41 @@
+import { createDomain } from "effector";
+import { root } from "effector-root";
+import { shared } from "./shared";
+const app = createDomain({
+    sid: "2d2t7lkj5xp5m",
+    name: "app"
+});
+const $store = app.store(0, {
+    sid: "14q6yc7hepxj",
+    name: "$store"
+});
+const inner = app.createDomain({
+    sid: "38pkp2a3oovsq",
+    name: "inner"
+});
+const event = inner.event({
+    sid: "1bauzjh23qr8w",
+    name: "event"
+});
+const $rootStore = root.createStore(0, {
+    sid: "spue2h41a2x7",
+    name: "$rootStore"
+});
+const $sharedStore = shared.createStore(0, {
+    sid: "3qvmam1cd0ut0",
+    name: "$sharedStore"
+});
+function createModel(domain) {
+    return domain.createEvent({
+        sid: "153n8y3r8tg8e",
+        name: "name"
+    });
+}
+const analytics = {
+    event (name) {}
+};
+createDomain({
+    sid: "1i1eso8mznexp",
+    name: "analytics"
+});
+const click = analytics.event("click");
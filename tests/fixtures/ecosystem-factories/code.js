@@ -0,0 +1,12 @@
+import { createEvent } from "effector";
+import { debounce } from "patronum";
+import { delay } from "patronum/delay";
+import { createQuery, retry } from "@farfetched/core";
+import { createRoute } from "atomic-router";
+
+const trigger = createEvent();
+const debounced = debounce({ source: trigger, timeout: 100 });
+const delayed = delay({ source: trigger, timeout: 100 });
+const userQuery = createQuery({ handler: async () => null });
+retry(userQuery, { times: 3 });
+const route = createRoute();
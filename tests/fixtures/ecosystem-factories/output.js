@@ -0,0 +1,44 @@
+import { createEvent, withFactory as _withFactory$0 } from "effector";
+import { debounce } from "patronum";
+import { delay } from "patronum/delay";
+import { createQuery, retry } from "@farfetched/core";
+import { createRoute } from "atomic-router";
+const trigger = createEvent({
+    sid: "2qiasqplgkjer",
+    name: "trigger"
+});
+const debounced = _withFactory$0({
+    sid: "1sk5qyz76oqtp",
+    fn: ()=>debounce({
+            source: trigger,
+            timeout: 100
+        }),
+    name: "debounced",
+    method: "debounce"
+});
+const delayed = _withFactory$0({
+    sid: "2ynnqs0wpg7z6",
+    fn: ()=>delay({
+            source: trigger,
+            timeout: 100
+        }),
+    name: "delayed",
+    method: "delay"
+});
+const userQuery = _withFactory$0({
+    sid: "4qxef7owejgu",
+    fn: ()=>createQuery({
+            handler: async ()=>null
+        }),
+    name: "userQuery",
+    method: "createQuery"
+});
+retry(userQuery, {
+    times: 3
+});
+const route = _withFactory$0({
+    sid: "2uux9fl7mvktl",
+    fn: ()=>createRoute(),
+    name: "route",
+    method: "createRoute"
+});
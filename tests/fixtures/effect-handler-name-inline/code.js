@@ -0,0 +1,9 @@
+import { createEffect, createEvent, sample } from "effector";
+
+export default createEffect(async function loadUser() {});
+
+createEffect({ handler: async function saveUser() {} });
+
+const started = createEvent();
+
+sample({ clock: started, target: createEffect(async function fetchUser() {}) });
@@ -0,0 +1,30 @@
+import { createEffect, createEvent, sample } from "effector";
+export default createEffect(async function loadUser() {}, {
+    sid: "ur4nqlksomhi",
+    name: "loadUser"
+});
+createEffect({
+    handler: async function saveUser() {}
+}, {
+    sid: "13bga5zuy7y4t",
+    name: "saveUser"
+});
+const started = createEvent({
+    sid: "n2xmndue9in7",
+    name: "started"
+});
+sample({
+    and: [
+        {
+            clock: started,
+            target: createEffect(async function fetchUser() {}, {
+                sid: "3cxz33r8f5z60",
+                name: "fetchUser"
+            })
+        }
+    ],
+    or: {
+        sid: "eezwd2w9la79",
+        name: "name"
+    }
+});
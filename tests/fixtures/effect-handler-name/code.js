@@ -0,0 +1,11 @@
+import { createEffect, createDomain } from "effector";
+
+async function fetchUser(id) {}
+
+createEffect(async function fetchPosts(id) {});
+createEffect(fetchUser);
+createEffect(async (id) => {});
+createEffect({ handler: fetchUser });
+
+const app = createDomain();
+const fetchUserFx = app.effect(fetchUser);
@@ -0,0 +1,28 @@
+import { createEffect, createDomain } from "effector";
+async function fetchUser(id) {}
+createEffect(async function fetchPosts(id) {}, {
+    sid: "1ipuq3d3zwsux",
+    name: "fetchPosts"
+});
+createEffect(fetchUser, {
+    sid: "3bl6xjupld4rq",
+    name: "fetchUser"
+});
+createEffect(async (id)=>{}, {
+    sid: "328nfx17ebogy",
+    name: "name"
+});
+createEffect({
+    handler: fetchUser
+}, {
+    sid: "1h80msnj993s4",
+    name: "fetchUser"
+});
+const app = createDomain({
+    sid: "6io21clkenzf",
+    name: "app"
+});
+const fetchUserFx = app.effect(fetchUser, {
+    sid: "2j8w0hnk0vuax",
+    name: "fetchUserFx"
+});
@@ -1,7 +1,6 @@
 import createFactory from './factory';
-import { createEvent } from "effector";
+import { createEvent, withFactory as _withFactory$0 } from "effector";
 import { createFactory2 } from './factory';
-import { withFactory as _withFactory$0 } from "effector";
 var _effectorFileName$0 = "/output.js";
 const x = _withFactory$0({
     sid: "a4ipirl4gkpj",
@@ -14,7 +13,7 @@ const x = _withFactory$0({
             },
             name: "x"
         })),
-    name: "name",
+    name: "x",
     method: "default",
     loc: {
         file: _effectorFileName$0,
@@ -71,7 +70,7 @@ const nested2 = _withFactory$0({
             sid: "1rtit3y9omj0f",
             fn: ()=>createFactory2(222),
             name: "nested2",
-            method: "createFactory2#1",
+            method: "createFactory2",
             loc: {
                 file: _effectorFileName$0,
                 line: 10,
@@ -102,7 +101,7 @@ const nested3 = _withFactory$0({
                     }
                 })),
             name: "nested3",
-            method: "createFactory2#1",
+            method: "createFactory2",
             loc: {
                 file: _effectorFileName$0,
                 line: 11,
@@ -0,0 +1,7 @@
+import { createX } from "~/features/x";
+import { createModel } from "./features/x/index";
+import { createToggle } from "./shared/factory.mjs";
+
+const x = createX();
+const model = createModel();
+const toggle = createToggle();
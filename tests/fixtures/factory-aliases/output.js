@@ -0,0 +1,22 @@
+import { createX } from "~/features/x";
+import { createModel } from "./features/x/index";
+import { createToggle } from "./shared/factory.mjs";
+import { withFactory as _withFactory$0 } from "effector";
+const x = _withFactory$0({
+    sid: "a4ipirl4gkpj",
+    fn: ()=>createX(),
+    name: "x",
+    method: "createX"
+});
+const model = _withFactory$0({
+    sid: "2s8wb3hmr0pui",
+    fn: ()=>createModel(),
+    name: "model",
+    method: "createModel"
+});
+const toggle = _withFactory$0({
+    sid: "mi6dwr5aos4h",
+    fn: ()=>createToggle(),
+    name: "toggle",
+    method: "createToggle"
+});
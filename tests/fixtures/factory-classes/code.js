@@ -0,0 +1,6 @@
+import { FormModel } from "./models";
+import * as models from "./models";
+
+const form = new FormModel({ fields: [] });
+const list = new models.ListModel();
+const date = new Date();
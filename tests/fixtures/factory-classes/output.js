@@ -0,0 +1,18 @@
+import { FormModel } from "./models";
+import * as models from "./models";
+import { withFactory as _withFactory$0 } from "effector";
+const form = _withFactory$0({
+    sid: "2expk69nq6nii",
+    fn: ()=>new FormModel({
+            fields: []
+        }),
+    name: "form",
+    method: "FormModel"
+});
+const list = _withFactory$0({
+    sid: "29b95aiuvk61r",
+    fn: ()=>new models.ListModel(),
+    name: "list",
+    method: "ListModel"
+});
+const date = new Date();
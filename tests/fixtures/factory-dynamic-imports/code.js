@@ -0,0 +1,13 @@
+import { render } from "./render";
+
+export async function setup() {
+  const { createWidget, createPanel: makePanel } = await import("./widget-factory");
+  const models = await import("./models");
+
+  const widget = createWidget();
+  const panel = makePanel({ title: "panel" });
+  const form = models.createForm();
+  const list = models.createList();
+
+  render(widget, panel, form, list);
+}
@@ -0,0 +1,28 @@
+import { render } from "./render";
+import { withFactory as _withFactory$0 } from "effector";
+export async function setup() {
+    const { createWidget, createPanel: makePanel } = await import("./widget-factory");
+    const models = await import("./models");
+    const widget = _withFactory$0({
+        sid: "76kyssuayhb6",
+        fn: ()=>createWidget(),
+        name: "widget",
+        method: "createWidget"
+    });
+    const panel = _withFactory$0({
+        sid: "d2887q7ygmoq",
+        fn: ()=>makePanel({
+                title: "panel"
+            }),
+        name: "panel",
+        method: "createPanel"
+    });
+    const form = _withFactory$0({
+        sid: "3tjsa0gnkbpho",
+        fn: ()=>models.createForm(),
+        name: "form",
+        method: "createForm"
+    });
+    const list = models.createList();
+    render(widget, panel, form, list);
+}
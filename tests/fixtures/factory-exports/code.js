@@ -0,0 +1,7 @@
+import { createCounter, COUNTER_DEFAULT, formatCount } from "./model";
+import createForm, { validate } from "./form";
+
+const counter = createCounter(COUNTER_DEFAULT);
+const label = formatCount(1);
+const form = createForm();
+const valid = validate(form);
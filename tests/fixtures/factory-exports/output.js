@@ -0,0 +1,17 @@
+import { createCounter, COUNTER_DEFAULT, formatCount } from "./model";
+import createForm, { validate } from "./form";
+import { withFactory as _withFactory$0 } from "effector";
+const counter = _withFactory$0({
+    sid: "2zwdoedo9mt5n",
+    fn: ()=>createCounter(COUNTER_DEFAULT),
+    name: "counter",
+    method: "createCounter"
+});
+const label = formatCount(1);
+const form = _withFactory$0({
+    sid: "18ebnifhvqw79",
+    fn: ()=>createForm(),
+    name: "form",
+    method: "default"
+});
+const valid = validate(form);
@@ -0,0 +1,13 @@
+import { createToggle } from "./shared/factories/toggle";
+import { createField } from "./shared/factories/form/field.js";
+import { createUser } from "@company/models/user";
+import { createList } from "@company/models/user/list";
+import { createWidget } from "./features/widget";
+import { formatDate } from "./shared/utils";
+
+const toggle = createToggle();
+const field = createField();
+const user = createUser();
+const list = createList();
+const widget = createWidget();
+const date = formatDate();
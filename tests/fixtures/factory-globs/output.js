@@ -0,0 +1,33 @@
+import { createToggle } from "./shared/factories/toggle";
+import { createField } from "./shared/factories/form/field.js";
+import { createUser } from "@company/models/user";
+import { createList } from "@company/models/user/list";
+import { createWidget } from "./features/widget";
+import { formatDate } from "./shared/utils";
+import { withFactory as _withFactory$0 } from "effector";
+const toggle = _withFactory$0({
+    sid: "10l3p468wj19z",
+    fn: ()=>createToggle(),
+    name: "toggle",
+    method: "createToggle"
+});
+const field = _withFactory$0({
+    sid: "3fn9lvp9jwuod",
+    fn: ()=>createField(),
+    name: "field",
+    method: "createField"
+});
+const user = _withFactory$0({
+    sid: "10ka190nieqo1",
+    fn: ()=>createUser(),
+    name: "user",
+    method: "createUser"
+});
+const list = createList();
+const widget = _withFactory$0({
+    sid: "2i2nysaiaequg",
+    fn: ()=>createWidget(),
+    name: "widget",
+    method: "createWidget"
+});
+const date = formatDate();
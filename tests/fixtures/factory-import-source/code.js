@@ -0,0 +1,5 @@
+import { createStore } from "effector/compat";
+import { createCounter } from "./counter";
+
+const $total = createStore(0);
+const counter = createCounter();
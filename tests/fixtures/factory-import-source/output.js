@@ -0,0 +1,12 @@
+import { createStore, withFactory as _withFactory$0 } from "effector/compat";
+import { createCounter } from "./counter";
+const $total = createStore(0, {
+    sid: "2ae1w0y23behw",
+    name: "$total"
+});
+const counter = _withFactory$0({
+    sid: "hcuvqv642wi0",
+    fn: ()=>createCounter(),
+    name: "counter",
+    method: "createCounter"
+});
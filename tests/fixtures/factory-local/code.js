@@ -0,0 +1,18 @@
+import { createStore } from "effector";
+
+/* @effector-factory */
+function createToggle() {
+  const $on = createStore(false);
+  return $on;
+}
+
+const createList = () => createStore([]);
+
+function format(value) {
+  return String(value);
+}
+
+export const a = createToggle();
+export const b = createToggle();
+export const list = createList();
+export const label = format(1);
@@ -0,0 +1,35 @@
+import { createStore, withFactory as _withFactory$0 } from "effector";
+/* @effector-factory */
+function createToggle() {
+    const $on = createStore(false, {
+        sid: "2l26adw2w9sih",
+        name: "$on"
+    });
+    return $on;
+}
+const createList = ()=>createStore([], {
+        sid: "1dwur76nqekhr",
+        name: "createList"
+    });
+function format(value) {
+    return String(value);
+}
+export const a = _withFactory$0({
+    sid: "2j1b6u29in8tu",
+    fn: ()=>createToggle(),
+    name: "a",
+    method: "createToggle"
+});
+export const b = _withFactory$0({
+    sid: "3jr3q90ah6886",
+    fn: ()=>createToggle(),
+    name: "b",
+    method: "createToggle"
+});
+export const list = _withFactory$0({
+    sid: "3l6w058h2vf2p",
+    fn: ()=>createList(),
+    name: "list",
+    method: "createList"
+});
+export const label = format(1);
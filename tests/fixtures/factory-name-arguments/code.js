@@ -0,0 +1,4 @@
+import { createEvent } from "effector";
+import { createModel } from "./model";
+
+const model = createModel({ reset: createEvent(), timeout: 100 });
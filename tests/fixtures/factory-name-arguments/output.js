@@ -0,0 +1,14 @@
+import { createEvent, withFactory as _withFactory$0 } from "effector";
+import { createModel } from "./model";
+const model = _withFactory$0({
+    sid: "195jklprnmwn7",
+    fn: ()=>createModel({
+            reset: createEvent({
+                sid: "hxxzzmqwi59z",
+                name: "reset"
+            }),
+            timeout: 100
+        }),
+    name: "model",
+    method: "createModel"
+});
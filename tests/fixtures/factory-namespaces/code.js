@@ -0,0 +1,8 @@
+import * as factories from "./factories";
+import models from "./models";
+import * as utils from "./utils";
+
+const counter = factories.createCounter();
+const form = models.createForm({ fields: [] });
+const list = models.createList();
+const date = utils.formatDate();
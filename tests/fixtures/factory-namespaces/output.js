@@ -0,0 +1,20 @@
+import * as factories from "./factories";
+import models from "./models";
+import * as utils from "./utils";
+import { withFactory as _withFactory$0 } from "effector";
+const counter = _withFactory$0({
+    sid: "2j2za596w5016",
+    fn: ()=>factories.createCounter(),
+    name: "counter",
+    method: "createCounter"
+});
+const form = _withFactory$0({
+    sid: "1pexk2urm3d7f",
+    fn: ()=>models.createForm({
+            fields: []
+        }),
+    name: "form",
+    method: "createForm"
+});
+const list = models.createList();
+const date = utils.formatDate();
@@ -0,0 +1,6 @@
+/* @effector-factory */
+function createToggle() {
+  return { enabled: false };
+}
+
+const toggle = createToggle();
@@ -0,0 +1,12 @@
+/* @effector-factory */ import { withFactory as _withFactory$0 } from "effector";
+function createToggle() {
+    return {
+        enabled: false
+    };
+}
+const toggle = _withFactory$0({
+    sid: "2byilb9e62i9r",
+    fn: ()=>createToggle(),
+    name: "toggle",
+    method: "createToggle"
+});
@@ -0,0 +1,5 @@
+import { createCounter } from "./counter";
+
+const counter = createCounter();
+
+import { withFactory } from "effector";
@@ -0,0 +1,8 @@
+import { createCounter } from "./counter";
+const counter = withFactory({
+    sid: "97fxl917p89e",
+    fn: ()=>createCounter(),
+    name: "counter",
+    method: "createCounter"
+});
+import { withFactory } from "effector";
@@ -0,0 +1,4 @@
+import { withFactory } from "effector";
+import { createCounter } from "./counter";
+
+const counter = createCounter();
@@ -0,0 +1,8 @@
+import { withFactory } from "effector";
+import { createCounter } from "./counter";
+const counter = withFactory({
+    sid: "2zwdoedo9mt5n",
+    fn: ()=>createCounter(),
+    name: "counter",
+    method: "createCounter"
+});
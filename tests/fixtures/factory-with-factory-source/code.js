@@ -0,0 +1,4 @@
+import { createStore } from "effector";
+import { createCounter } from "./counter";
+
+const counter = createCounter();
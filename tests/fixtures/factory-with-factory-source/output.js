@@ -0,0 +1,9 @@
+import { createStore } from "effector";
+import { createCounter } from "./counter";
+import { withFactory as _withFactory$0 } from "@/shared/effector";
+const counter = _withFactory$0({
+    sid: "2zwdoedo9mt5n",
+    fn: ()=>createCounter(),
+    name: "counter",
+    method: "createCounter"
+});
@@ -0,0 +1,2 @@
+import { createStore } from 'effector';
+const $demo = createStore(0)
\ No newline at end of file
@@ -0,0 +1,9 @@
+import { createStore } from 'effector';
+const $demo = createStore(0, {
+    sid: "27smw5if358nn",
+    loc: {
+        line: 2,
+        column: 14
+    },
+    name: "$demo"
+});
@@ -0,0 +1,10 @@
+import { createStore } from 'effector';
+const $demo = createStore(0, {
+    sid: "27smw5if358nn",
+    loc: {
+        file: "output.js",
+        line: 2,
+        column: 14
+    },
+    name: "$demo"
+});
@@ -0,0 +1,4 @@
+import { createStore } from 'effector';
+const $demo = createStore(0);
+const $other = createStore(1);
+//# sourceMappingURL=data:application/json;base64,eyJ2ZXJzaW9uIjozLCJzb3VyY2VzIjpbIm9yaWdpbmFsLnRzIl0sIm5hbWVzIjpbXSwibWFwcGluZ3MiOiI7Y0FTSSJ9
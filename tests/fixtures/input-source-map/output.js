@@ -0,0 +1,21 @@
+import { createStore } from 'effector';
+var _effectorFileName$0 = "/output.js";
+const $demo = createStore(0, {
+    sid: "27smw5if358nn",
+    loc: {
+        file: "/original.ts",
+        line: 10,
+        column: 4
+    },
+    name: "$demo"
+});
+const $other = createStore(1, {
+    sid: "2x3npbgw1lht7",
+    loc: {
+        file: _effectorFileName$0,
+        line: 3,
+        column: 15
+    },
+    name: "$other"
+});
+//# sourceMappingURL=data:application/json;base64,eyJ2ZXJzaW9uIjozLCJzb3VyY2VzIjpbIm9yaWdpbmFsLnRzIl0sIm5hbWVzIjpbXSwibWFwcGluZ3MiOiI7Y0FTSSJ9
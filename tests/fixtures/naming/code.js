@@ -0,0 +1,5 @@
+import { createStore, createEvent, createEffect } from "effector";
+
+createEvent();
+const $user = createStore(null);
+const fetchUserFx = createEffect();
@@ -0,0 +1,13 @@
+import { createStore, createEvent, createEffect } from "effector";
+createEvent({
+    sid: "xcofchsbmkd8",
+    name: "output/event/anon:3"
+});
+const $user = createStore(null, {
+    sid: "114x004yaw69l",
+    name: "output/store/user:4"
+});
+const fetchUserFx = createEffect({
+    sid: "1rkgzxng43nlr",
+    name: "output/effect/fetchUser:5"
+});
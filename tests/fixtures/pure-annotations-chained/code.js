@@ -0,0 +1,11 @@
+import { createEvent, createStore, createDomain } from "effector";
+
+const log = console.log;
+const $a = createStore(0);
+const changed = createEvent();
+
+const unwatch = createEvent().watch(log);
+const $count = createStore(0).on(changed, (count) => count + 1);
+const unwatchDoubled = $a.map((a) => a * 2).watch(log);
+const $doubled = createStore(1).map((a) => a * 2);
+const event = createDomain().createEvent();
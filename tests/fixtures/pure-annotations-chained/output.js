@@ -0,0 +1,32 @@
+import { createEvent, createStore, createDomain } from "effector";
+const log = console.log;
+const $a = /*#__PURE__*/ createStore(0, {
+    sid: "z81wbeopmbf2",
+    name: "$a"
+});
+const changed = /*#__PURE__*/ createEvent({
+    sid: "2sn8w5u0lhfay",
+    name: "changed"
+});
+const unwatch = createEvent({
+    sid: "13phluqv4iyj8",
+    name: "unwatch"
+}).watch(log);
+const $count = createStore(0, {
+    sid: "3fclynytqon59",
+    name: "$count"
+}).on(changed, (count)=>count + 1);
+const unwatchDoubled = $a.map((a)=>a * 2, {
+    name: "unwatchDoubled"
+}).watch(log);
+const $doubled = createStore(1, {
+    sid: "3nac21csk69jx",
+    name: "$doubled"
+}).map((a)=>a * 2);
+const event = /*#__PURE__*/ createDomain({
+    sid: "2i294akhvolv5",
+    name: "event"
+}).createEvent({
+    sid: "2qqxmdxtomu5a",
+    name: "event"
+});
@@ -0,0 +1,14 @@
+import { createEvent, createStore, sample } from "effector";
+
+/* @effector-factory */
+function createCounter() {
+  const $count = createStore(0);
+  return $count;
+}
+
+const increment = createEvent();
+const $total = createStore(0);
+
+const updated = sample({ clock: increment, target: $total });
+
+export const counter = createCounter();
@@ -0,0 +1,35 @@
+import { createEvent, createStore, sample, withFactory as _withFactory$0 } from "effector";
+/* @effector-factory */
+function createCounter() {
+    const $count = /*#__PURE__*/ createStore(0, {
+        sid: "ugrb6tm2cbbz",
+        name: "$count"
+    });
+    return $count;
+}
+const increment = /*#__PURE__*/ createEvent({
+    sid: "1jmy511q03h0l",
+    name: "increment"
+});
+const $total = /*#__PURE__*/ createStore(0, {
+    sid: "dpruzeiuznoo",
+    name: "$total"
+});
+const updated = sample({
+    and: [
+        {
+            clock: increment,
+            target: $total
+        }
+    ],
+    or: {
+        sid: "31mfzd5dwmh5d",
+        name: "updated"
+    }
+});
+export const counter = /*#__PURE__*/ _withFactory$0({
+    sid: "1yovc3vl1axqz",
+    fn: ()=>createCounter(),
+    name: "counter",
+    method: "createCounter"
+});
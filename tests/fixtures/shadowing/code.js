@@ -0,0 +1,14 @@
+import { createStore, createEvent, sample } from "effector";
+
+function setup(createStore) {
+  return createStore(0);
+}
+
+function local() {
+  const sample = (config) => config;
+  return sample({ clock: 1 });
+}
+
+const trigger = createEvent();
+const $count = createStore(0);
+const linked = sample({ clock: trigger, target: $count });
@@ -0,0 +1,30 @@
+import { createStore, createEvent, sample } from "effector";
+function setup(createStore) {
+    return createStore(0);
+}
+function local() {
+    const sample = (config)=>config;
+    return sample({
+        clock: 1
+    });
+}
+const trigger = createEvent({
+    sid: "1vl8stjnmevvu",
+    name: "trigger"
+});
+const $count = createStore(0, {
+    sid: "wa1kztf3hekd",
+    name: "$count"
+});
+const linked = sample({
+    and: [
+        {
+            clock: trigger,
+            target: $count
+        }
+    ],
+    or: {
+        sid: "19m4x1wlwxysa",
+        name: "linked"
+    }
+});
@@ -0,0 +1,8 @@
+import { createStore } from "effector";
+import { invoke } from "@withease/factories";
+import { createCounter } from "./counter";
+import * as models from "./models";
+
+const $count = createStore(0);
+const counter = invoke(createCounter, { initial: 0 });
+const form = invoke(models.createForm);
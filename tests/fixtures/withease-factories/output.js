@@ -0,0 +1,22 @@
+import { createStore, withFactory as _withFactory$0 } from "effector";
+import { invoke } from "@withease/factories";
+import { createCounter } from "./counter";
+import * as models from "./models";
+const $count = createStore(0, {
+    sid: "6862lu1b7rh8",
+    name: "$count"
+});
+const counter = _withFactory$0({
+    sid: "y6bv45zayilt",
+    fn: ()=>invoke(createCounter, {
+            initial: 0
+        }),
+    name: "counter",
+    method: "createCounter"
+});
+const form = _withFactory$0({
+    sid: "rrj5z56bfbk8",
+    fn: ()=>invoke(models.createForm),
+    name: "form",
+    method: "createForm"
+});
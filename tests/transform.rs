@@ -0,0 +1,298 @@
+use effector_swc_plugin::{Config, Effector, InternalConfig, PublicConfig};
+use swc_core::{
+    common::{FileName, FilePathMapping, Globals, Mark, SourceMap, DUMMY_SP, GLOBALS},
+    ecma::{
+        ast::*,
+        visit::{Visit, VisitMut, VisitMutWith, VisitWith},
+    },
+};
+use swc_ecmascript::{
+    parser::{parse_file_as_module, parse_file_as_script, Syntax},
+    transforms::resolver,
+};
+
+/// Runs the plugin on `code` parsed as a module or as a script. `prepare` is
+/// applied to the parsed program, e.g. to emulate other transforms.
+fn transform(
+    code: &str,
+    config: &str,
+    script: bool,
+    prepare: impl FnOnce(&mut Program),
+) -> Program {
+    transform_with(code, config, Syntax::Es(Default::default()), script, prepare)
+}
+
+/// Same as [transform], but parses `code` with the given `syntax`.
+fn transform_with(
+    code: &str,
+    config: &str,
+    syntax: Syntax,
+    script: bool,
+    prepare: impl FnOnce(&mut Program),
+) -> Program {
+    GLOBALS.set(&Globals::new(), || {
+        let cm = SourceMap::new(FilePathMapping::empty());
+        let fm = cm.new_source_file(FileName::Real("/app/model.js".into()), code.into());
+
+        let mut program = if script {
+            Program::Script(
+                parse_file_as_script(&fm, syntax, EsVersion::latest(), None, &mut vec![]).unwrap(),
+            )
+        } else {
+            Program::Module(
+                parse_file_as_module(&fm, syntax, EsVersion::latest(), None, &mut vec![]).unwrap(),
+            )
+        };
+
+        prepare(&mut program);
+
+        let public_config = serde_json::from_str::<PublicConfig>(config).unwrap();
+        let config = Config::new(public_config, InternalConfig::new(false));
+
+        program.visit_mut_with(&mut resolver(Mark::new(), Mark::new(), syntax.typescript()));
+        program.visit_mut_with(&mut Effector::new(config, Some("/app"), Some("/app/model.js"), cm));
+
+        program
+    })
+}
+
+fn callee_name(call: &CallExpr) -> Option<&str> {
+    match &call.callee {
+        Callee::Expr(expr) => match &**expr {
+            Expr::Ident(ident) => Some(&*ident.sym),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+struct Calls(Vec<CallExpr>);
+
+impl Visit for Calls {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        self.0.push(call.clone());
+        call.visit_children_with(self);
+    }
+}
+
+fn find_call(program: &Program, name: &str) -> CallExpr {
+    let mut calls = Calls::default();
+    program.visit_with(&mut calls);
+
+    calls.0.into_iter().find(|call| callee_name(call) == Some(name)).unwrap()
+}
+
+struct DropSpan(&'static str);
+
+impl VisitMut for DropSpan {
+    fn visit_mut_call_expr(&mut self, call: &mut CallExpr) {
+        if callee_name(call) == Some(self.0) {
+            call.span = DUMMY_SP;
+        }
+
+        call.visit_mut_children_with(self);
+    }
+}
+
+#[test]
+fn test_config_spans() {
+    let program = transform(
+        r#"
+import { createStore } from "effector";
+const $store = createStore(0);
+console.log(foo(bar()));
+"#,
+        "{}",
+        false,
+        |program| program.visit_mut_with(&mut DropSpan("bar")),
+    );
+
+    let call = find_call(&program, "createStore");
+    let Some(Expr::Object(config)) = call.args.get(1).map(|arg| &*arg.expr) else {
+        panic!("config is not added");
+    };
+
+    assert_eq!((config.span.lo, config.span.hi), (call.span.lo, call.span.hi));
+
+    for prop in &config.props {
+        let PropOrSpread::Prop(prop) = prop else { continue };
+        let Prop::KeyValue(KeyValueProp { key: PropName::Ident(key), .. }) = &**prop else {
+            continue;
+        };
+
+        assert!(!key.span.is_dummy(), "{} has no span", key.sym);
+    }
+
+    // Calls the plugin doesn't touch keep spans set by other transforms
+    assert!(find_call(&program, "bar").span.is_dummy());
+}
+
+#[test]
+fn test_factory_spans() {
+    let program = transform(
+        r#"
+import { createCounter } from "./counter";
+const counter = createCounter();
+"#,
+        r#"{"factories": ["./counter"]}"#,
+        false,
+        |_| {},
+    );
+
+    let call = find_call(&program, "createCounter");
+    let wrapper = find_call(&program, "_withFactory$0");
+
+    assert_eq!((wrapper.span.lo, wrapper.span.hi), (call.span.lo, call.span.hi));
+}
+
+fn script_body(program: Program) -> Vec<Stmt> {
+    match program {
+        Program::Script(script) => script.body,
+        Program::Module(_) => panic!("expected a script"),
+    }
+}
+
+fn is_use_strict(stmt: &Stmt) -> bool {
+    matches!(
+        stmt,
+        Stmt::Expr(ExprStmt { expr, .. })
+            if matches!(&**expr, Expr::Lit(Lit::Str(str)) if &*str.value == "use strict")
+    )
+}
+
+fn var_declarator(stmt: &Stmt) -> &VarDeclarator {
+    match stmt {
+        Stmt::Decl(Decl::Var(var)) => &var.decls[0],
+        _ => panic!("expected a variable declaration"),
+    }
+}
+
+#[test]
+fn test_script_file_name() {
+    let body = script_body(transform(
+        r#"
+"use strict";
+var $store = createStore(0);
+"#,
+        r#"{"addLoc": true}"#,
+        true,
+        |_| {},
+    ));
+
+    assert!(is_use_strict(&body[0]));
+
+    let Pat::Ident(name) = &var_declarator(&body[1]).name else {
+        panic!("file name is not declared after the prologue");
+    };
+
+    assert_eq!(&*name.id.sym, "_effectorFileName$0");
+}
+
+#[test]
+fn test_script_compact_loc() {
+    let body = script_body(transform(
+        r#"
+"use strict";
+var $store = createStore(0);
+"#,
+        r#"{"addLoc": true, "compactLoc": true}"#,
+        true,
+        |_| {},
+    ));
+
+    assert!(is_use_strict(&body[0]));
+
+    // function _effectorLoc$0(line, column) { return { file, line, column } }
+    let Stmt::Decl(Decl::Fn(helper)) = &body[1] else {
+        panic!("loc helper is not declared after the prologue");
+    };
+
+    assert_eq!(&*helper.ident.sym, "_effectorLoc$0");
+    assert_eq!(helper.function.params.len(), 2);
+
+    let Some(Expr::Call(store)) = var_declarator(&body[2]).init.as_deref() else {
+        panic!("store is not created");
+    };
+    let Some(Expr::Object(config)) = store.args.get(1).map(|arg| &*arg.expr) else {
+        panic!("store has no config");
+    };
+    let has_helper_call = config.props.iter().any(|prop| {
+        matches!(
+            prop,
+            PropOrSpread::Prop(prop) if matches!(
+                &**prop,
+                Prop::KeyValue(KeyValueProp { value, .. })
+                    if matches!(&**value, Expr::Call(call) if callee_name(call) == Some("_effectorLoc$0"))
+            )
+        )
+    });
+
+    assert!(has_helper_call);
+}
+
+#[test]
+fn test_script_require() {
+    let body = script_body(transform(
+        r#"
+"use strict";
+function createCounter() {
+    return createStore(0);
+}
+var counter = createCounter();
+"#,
+        r#"{"factories": ["./model"]}"#,
+        true,
+        |_| {},
+    ));
+
+    assert!(is_use_strict(&body[0]));
+
+    // var { withFactory: _withFactory$0 } = require("effector")
+    let require = var_declarator(&body[1]);
+    let Pat::Object(pat) = &require.name else {
+        panic!("withFactory is not required after the prologue");
+    };
+    let ObjectPatProp::KeyValue(KeyValuePatProp { key: PropName::Ident(key), .. }) = &pat.props[0]
+    else {
+        panic!("withFactory is not destructured");
+    };
+
+    assert_eq!(&*key.sym, "withFactory");
+
+    let Some(Expr::Call(call)) = require.init.as_deref() else {
+        panic!("withFactory is not required");
+    };
+    let Some(Expr::Lit(Lit::Str(source))) = call.args.first().map(|arg| &*arg.expr) else {
+        panic!("require has no source");
+    };
+
+    assert_eq!(callee_name(call), Some("require"));
+    assert_eq!(&*source.value, "effector");
+
+    let Some(Expr::Call(counter)) = var_declarator(&body[3]).init.as_deref() else {
+        panic!("factory call is not wrapped");
+    };
+
+    assert_eq!(callee_name(counter), Some("_withFactory$0"));
+}
+
+#[test]
+fn test_type_only_with_factory() {
+    let program = transform_with(
+        r#"
+import { type withFactory } from "effector";
+import { createCounter } from "./counter";
+const counter = createCounter();
+"#,
+        r#"{"factories": ["./counter"]}"#,
+        Syntax::Typescript(Default::default()),
+        false,
+        |_| {},
+    );
+
+    // Type-only imports are erased, so withFactory is imported again
+    let wrapper = find_call(&program, "_withFactory$0");
+
+    assert!(matches!(&*wrapper.args[0].expr, Expr::Object(_)));
+}